@@ -0,0 +1,331 @@
+# German. Messages missing here are shown in English.
+
+## Shared
+
+save = Speichern
+cancel = Abbrechen
+delete = Löschen
+field-label = { $field }:
+
+## Categories
+
+category-watching = Am Schauen
+category-plan-to-watch = Geplant
+category-completed = Abgeschlossen
+category-all = Alle
+category-label = Kategorie:
+category-button = { $category } ({ $count })
+
+## Profiles
+
+profile-label = Profil:
+profile-new-hint = Neues Profil
+profile-add = Profil hinzufügen
+profile-watched-together = Gemeinsam geschaut
+
+## Search
+
+search-label = Suche:
+search-hint =
+    Nach Namen suchen, oder mit einer Abfrage filtern, etwa
+    cat:watching season>2 -"star" (ep<3 OR ep>=10)
+    Felder: name, alias, tag, notes, cat, season, episodes, rating,
+    idle (Tage seit der letzten Änderung)
+search-query-error-hint = Stattdessen werden einfache Namenstreffer gezeigt.
+hidden-duplicates-warning = Ausgeblendet: { $names }
+hidden-duplicates-hint = Ihr Name unterscheidet sich nur in der Groß- und Kleinschreibung von dem einer anderen Serie. Sie sind noch in der Datenbank, dort umbenannt werden sie wieder angezeigt.
+query-error-unmatched-close = `)` ohne passende `(`
+query-error-missing-close = schließende `)` fehlt
+query-error-missing-quote = schließendes `"` fehlt
+query-error-expected-term = Suchbegriff erwartet
+query-error-unknown-operator = unbekannter Operator `{ $operator }`
+query-error-missing-value = Wert für `{ $field }` fehlt
+query-error-not-comparable = `{ $field }` lässt sich nur mit `:`, `=` oder `!=` vergleichen
+query-error-unknown-category = unbekannte Kategorie `{ $category }`
+query-error-not-a-number = `{ $field }` erwartet eine Zahl, nicht `{ $value }`
+search-matches =
+    { $count ->
+        [one] { $count } Treffer
+       *[other] { $count } Treffer
+    }
+
+## Pages
+
+page-label = Seite:
+page-of = von { $count }
+page-size-label = Pro Seite:
+page-first-hint = Erste Seite (Pos1)
+page-previous-hint = Vorherige Seite (Bild auf)
+page-next-hint = Nächste Seite (Bild ab)
+page-last-hint = Letzte Seite (Ende)
+scroll = Scrollen
+scroll-hint = Durch alle Serien scrollen statt zu blättern
+
+## Toolbar
+
+sorting = Sortierung
+sorting-ignore-articles = Führende Artikel ignorieren
+sorting-articles-label = Artikel (durch Kommas getrennt):
+sorting-articles-hint = Artikel mit Apostroph am Ende, etwa l', werden auch ohne folgendes Leerzeichen übersprungen
+settings = Einstellungen
+statistics = Statistik
+shortcuts = Tastenkürzel
+
+## Smart filters
+
+filter-save-menu = Filter speichern
+filter-save-hint = Die aktuelle Suche und Kategorie als Filter speichern
+filter-name-label = Filtername:
+filter-delete = Filter löschen
+
+## Shows
+
+column-name = Name
+column-season = Staffel
+column-episodes = Folgen
+column-rating = Bewertung
+column-last-updated = Zuletzt geändert
+column-sort-hint = Nach { $column } sortieren
+group-expand = Ausklappen
+group-collapse = Einklappen
+drag-hint = Auf eine Kategorie ziehen, um die Serie dorthin zu verschieben, oder innerhalb von „Geplant“, um festzulegen, was als Nächstes geschaut wird
+row-delete = Entf
+row-details = Details
+name-label = Name:
+season-label = Staffel:
+episodes-label = Gesehene Folgen:
+rating-label = Bewertung:
+rating-hint = Von { $max }
+updated-never = Nie
+updated-today = Heute
+updated-yesterday = Gestern
+updated-days-ago =
+    { $days ->
+        [one] vor { $days } Tag
+       *[other] vor { $days } Tagen
+    }
+add-title = Neue Serie hinzufügen
+add = Hinzufügen
+add-error-name = Einen Namen eingeben
+add-error-duplicate = Schon in der Liste
+add-error-number = Eine ganze Zahl ab 0 eingeben
+add-error-rating = Leer lassen, oder eine ganze Zahl bis { $max } eingeben
+add-go-to-existing = Zur Serie
+add-similar-warning = Ähnliche Serien sind schon in der Liste:
+
+## Selection
+
+selection-page = Alle sichtbaren Serien auswählen
+selection-range-hint = Mit Umschalt-Klick alle Serien seit der zuletzt gewählten auswählen
+selection-count = { $count } ausgewählt
+selection-select-all = Alle { $count } auswählen
+selection-clear = Auswahl aufheben
+selection-move-to = Verschieben nach:
+selection-tag-hint = Schlagwort
+selection-add-tag = Schlagwort hinzufügen
+selection-remove-tag = Schlagwort entfernen
+selection-delete = Löschen…
+selection-export = Exportieren…
+selection-export-label = Exportieren nach:
+selection-export-hint = Pfad zu einer .csv-Datei
+selection-export-save = Exportieren
+selection-exported =
+    { $count ->
+        [one] Eine Serie nach { $path } exportiert
+       *[other] { $count } Serien nach { $path } exportiert
+    }
+selection-export-error = Export nach { $path } nicht möglich: { $error }
+
+## Deleting
+
+delete-question = { $name } löschen?
+delete-selection-question =
+    { $count ->
+        [one] Eine Serie löschen?
+       *[other] { $count } Serien löschen?
+    }
+delete-explanation =
+    { $count ->
+        [one] Die Serie wird aus allen Profilen entfernt, nicht nur aus diesem, samt allem Fortschritt darin.
+       *[other] Die Serien werden aus allen Profilen entfernt, nicht nur aus diesem, samt allem Fortschritt darin.
+    }
+delete-dont-ask = Bis zum Schließen von vcc nicht mehr fragen
+
+## Details
+
+details-close = Details schließen
+details-out-of-view = Der Fortschritt lässt sich nur ändern, solange die Serie zu sehen ist.
+details-last-updated = Zuletzt geändert:
+details-priority = Als Nächstes:
+details-rank = Nr. { $rank } in „Geplant“
+details-aliases = Alternativtitel:
+details-tags = Schlagwörter:
+details-notes = Notizen:
+details-list-hint = Durch Kommas getrennt
+details-activity = Verlauf
+details-no-activity = Noch keine Änderungen
+details-activity-time = { $date }, { $time }
+activity-category =
+    { $old ->
+        [none] Zu „{ $new }“ hinzugefügt
+       *[other] Von „{ $old }“ nach „{ $new }“ verschoben
+    }
+activity-season = Staffel { $old } → { $new }
+activity-episodes = Gesehene Folgen { $old } → { $new }
+activity-rating =
+    { $new ->
+        [none] Bewertung entfernt
+       *[other] Mit { $new } von { $max } bewertet
+    }
+activity-aliases =
+    { $new ->
+        [none] Alternativtitel entfernt
+       *[other] Alternativtitel: { $new }
+    }
+activity-tags =
+    { $new ->
+        [none] Schlagwörter entfernt
+       *[other] Schlagwörter: { $new }
+    }
+activity-notes = Notizen bearbeitet
+
+## Key bindings
+
+cheat-sheet-title = Tastenkürzel
+cheat-sheet-customize = Anpassen…
+keymap-title = Tastenbelegung
+keymap-unbound = Nicht belegt
+keymap-keys-hint = Tasten durch Kommas getrennt, etwa Ctrl+Shift+F, J
+keymap-default = Standard
+keymap-reset-all = Alle zurücksetzen
+keymap-save-error = Die Tastenbelegung kann nicht gespeichert werden und gilt nur bis zum Schließen von vcc: { $error }
+keymap-conflict = { $shortcut } löst sowohl „{ $action }“ als auch „{ $other_action }“ aus
+keymap-error-unknown-modifier = unbekannte Zusatztaste `{ $modifier }` in `{ $chord }`
+keymap-error-missing-key = Taste fehlt in `{ $chord }`
+keymap-error-unknown-key = unbekannte Taste `{ $key }`
+keymap-problem-expected-binding = keymap.conf Zeile { $line }: `action = keys` erwartet
+keymap-problem-unknown-action = keymap.conf Zeile { $line }: unbekannte Aktion `{ $action }`
+keymap-problem-bad-shortcut = keymap.conf Zeile { $line }: { $error }
+
+action-previous-row = Vorherige Serie auswählen
+action-next-row = Nächste Serie auswählen
+action-fewer-episodes = Eine Folge weniger
+action-more-episodes = Eine Folge mehr
+action-previous-season = Vorherige Staffel
+action-next-season = Nächste Staffel
+action-mark-watching = Nach „Am Schauen“ verschieben
+action-mark-plan-to-watch = Nach „Geplant“ verschieben
+action-mark-completed = Nach „Abgeschlossen“ verschieben
+action-raise-priority = In „Geplant“ früher schauen
+action-lower-priority = In „Geplant“ später schauen
+action-show-details = Details der Serie öffnen
+action-delete-show = Serie löschen
+action-remove-filter = Den fokussierten Filter löschen
+action-previous-page = Vorherige Seite
+action-next-page = Nächste Seite
+action-first-page = Erste Seite
+action-last-page = Letzte Seite
+action-focus-search = Suchen
+action-focus-add = Neue Serie hinzufügen
+action-cheat-sheet = Diese Tastenkürzel zeigen
+action-command-palette = Befehlspalette öffnen
+action-edit-keymap = Tastenbelegung bearbeiten
+action-show-statistics = Statistik der Sammlung zeigen
+action-show-settings = Einstellungen öffnen
+action-reset-layout = Fensteranordnung zurücksetzen
+
+## Command palette
+
+palette-hint = Befehl oder Serienname eingeben
+palette-switch-to = Wechseln zu { $name }
+palette-apply = { $name } anwenden
+palette-category = Kategorie
+palette-filter = Filter
+palette-profile = Profil
+palette-show = Serie
+
+## Statistics
+
+statistics-episodes-seen = Gesehene Folgen der aktuellen Staffeln
+statistics-average-rating = Durchschnittliche Bewertung
+statistics-completion-rate = Abschlussquote
+statistics-rating-value = { $rating } / { $max }
+statistics-percent = { $percent } %
+statistics-top-tags = Häufigste Schlagwörter
+statistics-no-tags = Noch keine Schlagwörter
+statistics-tag = { $tag } ({ $count })
+statistics-episodes-per-week = Folgen pro Woche
+statistics-episodes-per-month = Folgen pro Monat
+statistics-period-episodes =
+    { $count ->
+        [one] { $period }: { $count } Folge
+       *[other] { $period }: { $count } Folgen
+    }
+statistics-refresh = Aktualisieren
+statistics-report-line = { $label }: { $value }
+statistics-unknown-profile = Kein Profil namens { $name }.
+
+## Settings
+
+settings-language = Sprache:
+language-system = System
+settings-theme = Design:
+settings-theme-system = System
+settings-theme-light = Hell
+settings-theme-dark = Dunkel
+settings-scale = Skalierung:
+settings-font = Schriftart:
+settings-font-path-hint = Pfad zu einer .ttf- oder .otf-Datei
+settings-font-load = Laden
+settings-rows = Zeilen:
+settings-reset-layout = Anordnung zurücksetzen
+settings-reset-layout-hint = Fenstergröße und -position wiederherstellen
+font-default = Standard
+font-monospace = Festbreite
+font-file = Aus Datei
+font-error = { $path } kann nicht geladen werden: { $error }
+density-compact = Kompakt
+density-standard = Normal
+density-comfortable = Großzügig
+
+## Screenreader
+
+a11y-select = { $name } auswählen
+a11y-drag = { $name } ziehen
+a11y-delete = { $name } löschen
+a11y-details = Details zu { $name }
+a11y-previous-season = Vorherige Staffel von { $name }
+a11y-next-season = Nächste Staffel von { $name }
+a11y-season = Staffel von { $name }
+a11y-fewer-episodes = Eine Folge weniger von { $name }
+a11y-more-episodes = Eine Folge mehr von { $name }
+a11y-episodes = Gesehene Folgen von { $name }
+a11y-rating = Bewertung von { $name }
+a11y-category = Kategorie von { $name }
+a11y-row = { $category }, Staffel { $season }, { $episodes } Folgen gesehen, { $rating }, geändert: { $updated }
+a11y-rated = mit { $rating } von { $max } bewertet
+a11y-unrated = nicht bewertet
+a11y-decrease = { $field } verringern
+a11y-increase = { $field } erhöhen
+a11y-sorted-ascending = Aufsteigend sortiert
+a11y-sorted-descending = Absteigend sortiert
+a11y-letter = Serien, die mit { $letter } beginnen
+a11y-letter-other = Serien, die mit einer Ziffer oder einem Zeichen beginnen
+a11y-filter = { $search }. Mit Entf wird der Filter entfernt.
+
+## Dates
+
+date-day = { $day }. { $month }
+date-month = { $month } { $year }
+month-1 = Jan.
+month-2 = Feb.
+month-3 = März
+month-4 = Apr.
+month-5 = Mai
+month-6 = Juni
+month-7 = Juli
+month-8 = Aug.
+month-9 = Sept.
+month-10 = Okt.
+month-11 = Nov.
+month-12 = Dez.
@@ -0,0 +1,339 @@
+# The reference catalog. Every message shown anywhere in vcc lives here, and
+# messages missing from another language fall back to these.
+
+## Shared
+
+save = Save
+cancel = Cancel
+delete = Delete
+# Puts a colon after a field name, such as "Episodes seen:".
+field-label = { $field }:
+
+## Categories
+
+category-watching = Watching
+category-plan-to-watch = Plan to Watch
+category-completed = Completed
+category-all = All
+category-label = Category:
+# A category button, with the number of shows in it.
+category-button = { $category } ({ $count })
+
+## Profiles
+
+profile-label = Profile:
+profile-new-hint = New profile
+profile-add = Add Profile
+profile-watched-together = Watched together
+
+## Search
+
+search-label = Search:
+search-hint =
+    Search by name, or filter with a query such as
+    cat:watching season>2 -"star" (ep<3 OR ep>=10)
+    Fields: name, alias, tag, notes, cat, season, episodes, rating,
+    idle (days since the progress changed)
+search-query-error-hint = Showing plain name matches instead.
+hidden-duplicates-warning = Hidden: { $names }
+hidden-duplicates-hint = Their names only differ in case from another show's. They are still in the database, rename them there to see them again.
+query-error-unmatched-close = unmatched `)`
+query-error-missing-close = missing closing `)`
+query-error-missing-quote = missing closing `"`
+query-error-expected-term = expected a search term
+query-error-unknown-operator = unknown operator `{ $operator }`
+query-error-missing-value = missing value for `{ $field }`
+query-error-not-comparable = `{ $field }` can only be compared with `:`, `=` or `!=`
+query-error-unknown-category = unknown category `{ $category }`
+query-error-not-a-number = `{ $field }` expects a number, not `{ $value }`
+search-matches =
+    { $count ->
+        [one] { $count } match
+       *[other] { $count } matches
+    }
+
+## Pages
+
+page-label = Page Number:
+page-of = of { $count }
+page-size-label = Per Page:
+page-first-hint = First page (Home)
+page-previous-hint = Previous page (Page Up)
+page-next-hint = Next page (Page Down)
+page-last-hint = Last page (End)
+scroll = Scroll
+scroll-hint = Scroll through every show instead of paging
+
+## Toolbar
+
+sorting = Sorting
+sorting-ignore-articles = Ignore leading articles
+sorting-articles-label = Articles (comma separated):
+sorting-articles-hint = Articles ending in an apostrophe, such as l', are skipped even without a space after them
+settings = Settings
+statistics = Statistics
+shortcuts = Shortcuts
+
+## Smart filters
+
+filter-save-menu = Save Filter
+filter-save-hint = Save the current search and category as a filter
+filter-name-label = Filter name:
+filter-delete = Delete filter
+
+## Shows
+
+column-name = Name
+column-season = Season
+column-episodes = Episodes
+column-rating = Rating
+column-last-updated = Last Updated
+column-sort-hint = Sort by { $column }
+group-expand = Expand
+group-collapse = Collapse
+drag-hint = Drag onto a category to move the show there, or within Plan to Watch to change what's watched next
+row-delete = Del
+row-details = Details
+name-label = Name:
+season-label = Season Number:
+episodes-label = Episodes Seen:
+rating-label = Rating:
+rating-hint = Out of { $max }
+updated-never = Never
+updated-today = Today
+updated-yesterday = Yesterday
+updated-days-ago =
+    { $days ->
+        [one] { $days } day ago
+       *[other] { $days } days ago
+    }
+add-title = Add new show
+add = Add
+add-error-name = Enter a name
+add-error-duplicate = Already in your list
+add-error-number = Enter a whole number, 0 or more
+add-error-rating = Leave empty, or enter a whole number up to { $max }
+add-go-to-existing = Go to the show
+add-similar-warning = Similar shows are already in your list:
+
+## Selection
+
+selection-page = Select every show in view
+selection-range-hint = Shift-click to select every show from the last one picked
+selection-count = { $count } selected
+selection-select-all = Select all { $count }
+selection-clear = Clear selection
+selection-move-to = Move to:
+selection-tag-hint = Tag
+selection-add-tag = Add tag
+selection-remove-tag = Remove tag
+selection-delete = Delete…
+selection-export = Export…
+selection-export-label = Export to:
+selection-export-hint = Path to a .csv file
+selection-export-save = Export
+selection-exported =
+    { $count ->
+        [one] Exported one show to { $path }
+       *[other] Exported { $count } shows to { $path }
+    }
+selection-export-error = Unable to export to { $path }: { $error }
+
+## Deleting
+
+delete-question = Delete { $name }?
+delete-selection-question =
+    { $count ->
+        [one] Delete one show?
+       *[other] Delete { $count } shows?
+    }
+delete-explanation =
+    { $count ->
+        [one] The show is removed from every profile, not only this one, along with all of their progress on it.
+       *[other] The shows are removed from every profile, not only this one, along with all of their progress on them.
+    }
+delete-dont-ask = Don't ask again until vcc is closed
+
+## Details
+
+details-close = Close the details
+details-out-of-view = Progress can only be changed while the show is in view.
+details-last-updated = Last updated:
+details-priority = Watch next:
+details-rank = #{ $rank } in Plan to Watch
+details-aliases = Aliases:
+details-tags = Tags:
+details-notes = Notes:
+details-list-hint = Comma separated
+details-activity = Activity
+details-no-activity = No changes yet
+details-activity-time = { $date }, { $time }
+# An entry of the activity log. $old and $new are "none" where there was no
+# value.
+activity-category =
+    { $old ->
+        [none] Added to { $new }
+       *[other] Moved from { $old } to { $new }
+    }
+activity-season = Season { $old } → { $new }
+activity-episodes = Episodes seen { $old } → { $new }
+activity-rating =
+    { $new ->
+        [none] Rating removed
+       *[other] Rated { $new } out of { $max }
+    }
+activity-aliases =
+    { $new ->
+        [none] Aliases removed
+       *[other] Aliases set to { $new }
+    }
+activity-tags =
+    { $new ->
+        [none] Tags removed
+       *[other] Tags set to { $new }
+    }
+activity-notes = Notes edited
+
+## Key bindings
+
+cheat-sheet-title = Keyboard Shortcuts
+cheat-sheet-customize = Customize…
+keymap-title = Key Bindings
+keymap-unbound = Unbound
+keymap-keys-hint = Comma separated keys, such as Ctrl+Shift+F, J
+keymap-default = Default
+keymap-reset-all = Reset All
+keymap-save-error = Unable to save the keymap, the keys only apply until vcc is closed: { $error }
+keymap-conflict = { $shortcut } triggers both "{ $action }" and "{ $other_action }"
+keymap-error-unknown-modifier = unknown modifier `{ $modifier }` in `{ $chord }`
+keymap-error-missing-key = missing key in `{ $chord }`
+keymap-error-unknown-key = unknown key `{ $key }`
+keymap-problem-expected-binding = keymap.conf line { $line }: expected `action = keys`
+keymap-problem-unknown-action = keymap.conf line { $line }: unknown action `{ $action }`
+keymap-problem-bad-shortcut = keymap.conf line { $line }: { $error }
+
+action-previous-row = Focus the previous show
+action-next-row = Focus the next show
+action-fewer-episodes = One episode fewer
+action-more-episodes = One episode more
+action-previous-season = Previous season
+action-next-season = Next season
+action-mark-watching = Move to Watching
+action-mark-plan-to-watch = Move to Plan to Watch
+action-mark-completed = Move to Completed
+action-raise-priority = Watch sooner, in Plan to Watch
+action-lower-priority = Watch later, in Plan to Watch
+action-show-details = Open the show's details
+action-delete-show = Delete the show
+action-remove-filter = Delete the focused smart filter
+action-previous-page = Previous page
+action-next-page = Next page
+action-first-page = First page
+action-last-page = Last page
+action-focus-search = Search
+action-focus-add = Add a new show
+action-cheat-sheet = Show these shortcuts
+action-command-palette = Open the command palette
+action-edit-keymap = Edit key bindings
+action-show-statistics = Show library statistics
+action-show-settings = Open the settings
+action-reset-layout = Reset the window layout
+
+## Command palette
+
+palette-hint = Type a command or show name
+palette-switch-to = Switch to { $name }
+palette-apply = Apply { $name }
+palette-category = Category
+palette-filter = Filter
+palette-profile = Profile
+palette-show = Show
+
+## Statistics
+
+statistics-episodes-seen = Episodes seen in current seasons
+statistics-average-rating = Average rating
+statistics-completion-rate = Completion rate
+statistics-rating-value = { $rating } / { $max }
+statistics-percent = { $percent }%
+statistics-top-tags = Top tags
+statistics-no-tags = No tags yet
+statistics-tag = { $tag } ({ $count })
+statistics-episodes-per-week = Episodes per week
+statistics-episodes-per-month = Episodes per month
+statistics-period-episodes =
+    { $count ->
+        [one] { $period }: { $count } episode
+       *[other] { $period }: { $count } episodes
+    }
+statistics-refresh = Refresh
+statistics-report-line = { $label }: { $value }
+statistics-unknown-profile = No profile named { $name }.
+
+## Settings
+
+settings-language = Language:
+language-system = System
+settings-theme = Theme:
+settings-theme-system = System
+settings-theme-light = Light
+settings-theme-dark = Dark
+settings-scale = UI scale:
+settings-font = Font:
+settings-font-path-hint = Path to a .ttf or .otf file
+settings-font-load = Load
+settings-rows = Rows:
+settings-reset-layout = Reset layout
+settings-reset-layout-hint = Restore the window size and position
+font-default = Default
+font-monospace = Monospace
+font-file = From file
+font-error = Unable to load { $path }: { $error }
+density-compact = Compact
+density-standard = Standard
+density-comfortable = Comfortable
+
+## Screen readers
+
+# What the controls repeated on every row are read out as, so each names its
+# show.
+a11y-select = Select { $name }
+a11y-drag = Drag { $name }
+a11y-delete = Delete { $name }
+a11y-details = Details of { $name }
+a11y-previous-season = Previous season of { $name }
+a11y-next-season = Next season of { $name }
+a11y-season = Season number of { $name }
+a11y-fewer-episodes = One episode fewer of { $name }
+a11y-more-episodes = One episode more of { $name }
+a11y-episodes = Episodes seen of { $name }
+a11y-rating = Rating of { $name }
+a11y-category = Category of { $name }
+# Read out after a show's name. $rating is a11y-rated or a11y-unrated.
+a11y-row = { $category }, season { $season }, { $episodes } episodes seen, { $rating }, updated { $updated }
+a11y-rated = rated { $rating } out of { $max }
+a11y-unrated = not rated
+a11y-decrease = Decrease { $field }
+a11y-increase = Increase { $field }
+a11y-sorted-ascending = Sorted ascending
+a11y-sorted-descending = Sorted descending
+a11y-letter = Shows starting with { $letter }
+a11y-letter-other = Shows starting with a digit or symbol
+a11y-filter = { $search }. Press Delete to remove the filter.
+
+## Dates
+
+date-day = { $month } { $day }
+date-month = { $month } { $year }
+month-1 = Jan
+month-2 = Feb
+month-3 = Mar
+month-4 = Apr
+month-5 = May
+month-6 = Jun
+month-7 = Jul
+month-8 = Aug
+month-9 = Sep
+month-10 = Oct
+month-11 = Nov
+month-12 = Dec
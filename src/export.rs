@@ -0,0 +1,77 @@
+use crate::show::{DisplayShow, ShowCategory};
+
+const CSV_HEADER: &str = "name,season,episodes,category,rating,last_updated,aliases,tags,notes";
+
+// Categories go by the names the search queries use for them.
+fn category_name(category: ShowCategory) -> &'static str {
+    match category {
+        ShowCategory::Watching => "watching",
+        ShowCategory::PlanToWatch => "plantowatch",
+        ShowCategory::Completed => "completed",
+    }
+}
+
+// Quoted only when it has to be, with quotes doubled.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+pub fn write_csv(path: &str, shows: &[DisplayShow]) -> Result<(), String> {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+    for show in shows {
+        let fields = [
+            show.name().as_str(),
+            &show.season_number,
+            &show.episodes_seen,
+            category_name(show.category),
+            &show.rating,
+            &show.last_updated.to_string(),
+            &show.details.aliases,
+            &show.details.tags,
+            &show.details.notes,
+        ];
+        csv.push_str(
+            &fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        csv.push('\n');
+    }
+
+    std::fs::write(path, csv).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_fields_are_not_quoted() {
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("Dark"), "Dark");
+        assert_eq!(csv_field("Doctor Who; 2005"), "Doctor Who; 2005");
+    }
+
+    #[test]
+    fn separators_are_quoted() {
+        assert_eq!(
+            csv_field("Love, Death & Robots"),
+            "\"Love, Death & Robots\""
+        );
+        assert_eq!(csv_field("first\nsecond"), "\"first\nsecond\"");
+        assert_eq!(csv_field("first\r\nsecond"), "\"first\r\nsecond\"");
+    }
+
+    #[test]
+    fn quotes_are_doubled() {
+        assert_eq!(csv_field("The \"Office\""), "\"The \"\"Office\"\"\"");
+        assert_eq!(csv_field("\""), "\"\"\"\"");
+    }
+}
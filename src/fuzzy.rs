@@ -0,0 +1,375 @@
+use memchr::memmem;
+
+// Score bands, so that any substring match outranks any subsequence match,
+// which in turn outranks any match that needed typo correction.
+const SUBSTRING_SCORE: i64 = 3000;
+const SUBSEQUENCE_SCORE: i64 = 2000;
+const TYPO_SCORE: i64 = 1000;
+
+const WORD_START_BONUS: i64 = 30;
+const CONSECUTIVE_BONUS: i64 = 15;
+const GAP_PENALTY: i64 = 2;
+const TYPO_PENALTY: i64 = 200;
+
+// Below this many characters a search term is too short for typo tolerance
+// to produce anything but noise.
+const MIN_TYPO_TOLERANT_LEN: usize = 4;
+
+pub struct FuzzyMatch {
+    pub score: i64,
+    // Character indices into the haystack that matched the needle.
+    pub positions: Vec<usize>,
+}
+
+// A search term prepared once and matched against many names.
+pub struct FuzzyMatcher<'a> {
+    needle: &'a str,
+    needle_chars: Vec<char>,
+    finder: memmem::Finder<'a>,
+}
+
+impl<'a> FuzzyMatcher<'a> {
+    pub fn new(needle: &'a str) -> Self {
+        FuzzyMatcher {
+            needle,
+            needle_chars: needle.chars().collect(),
+            finder: memmem::Finder::new(needle.as_bytes()),
+        }
+    }
+
+    pub fn find(&self, haystack: &str) -> Option<FuzzyMatch> {
+        if self.needle_chars.is_empty() {
+            return Some(FuzzyMatch {
+                score: 0,
+                positions: Vec::new(),
+            });
+        }
+
+        if let Some(byte_index) = self.finder.find(haystack.as_bytes()) {
+            return Some(self.substring_match(haystack, byte_index));
+        }
+
+        let haystack_chars = haystack.chars().collect::<Vec<_>>();
+
+        if let Some(fuzzy_match) = self.subsequence_match(&haystack_chars) {
+            return Some(fuzzy_match);
+        }
+
+        self.typo_match(&haystack_chars)
+    }
+
+    fn substring_match(&self, haystack: &str, byte_index: usize) -> FuzzyMatch {
+        let start = haystack[..byte_index].chars().count();
+        let positions = (start..start + self.needle_chars.len()).collect::<Vec<_>>();
+
+        let haystack_chars = haystack.chars().collect::<Vec<_>>();
+        let mut score = SUBSTRING_SCORE - start as i64;
+        if is_word_start(&haystack_chars, start) {
+            score += WORD_START_BONUS * 2;
+        }
+        if self.needle.len() == haystack.len() {
+            score += WORD_START_BONUS * 2;
+        }
+
+        FuzzyMatch { score, positions }
+    }
+
+    fn subsequence_match(&self, haystack: &[char]) -> Option<FuzzyMatch> {
+        let mut positions = Vec::with_capacity(self.needle_chars.len());
+        let mut needle_index = 0;
+
+        for (index, c) in haystack.iter().enumerate() {
+            if needle_index == self.needle_chars.len() {
+                break;
+            }
+            if *c == self.needle_chars[needle_index] {
+                positions.push(index);
+                needle_index += 1;
+            }
+        }
+
+        if needle_index != self.needle_chars.len() {
+            return None;
+        }
+
+        Some(FuzzyMatch {
+            score: SUBSEQUENCE_SCORE + position_score(haystack, &positions),
+            positions,
+        })
+    }
+
+    // Approximate substring matching (Sellers' algorithm) using the optimal
+    // string alignment distance, so that swapped letters count as one typo.
+    fn typo_match(&self, haystack: &[char]) -> Option<FuzzyMatch> {
+        let needle = &self.needle_chars;
+        if needle.len() < MIN_TYPO_TOLERANT_LEN {
+            return None;
+        }
+        let max_typos = needle.len() / MIN_TYPO_TOLERANT_LEN;
+
+        let rows = needle.len() + 1;
+        let columns = haystack.len() + 1;
+        let mut distances = vec![0usize; rows * columns];
+        let at = |row: usize, column: usize| row * columns + column;
+
+        for row in 1..rows {
+            distances[at(row, 0)] = row;
+            for column in 1..columns {
+                let substitution = usize::from(needle[row - 1] != haystack[column - 1]);
+                let mut distance = usize::min(
+                    distances[at(row - 1, column - 1)] + substitution,
+                    usize::min(
+                        distances[at(row - 1, column)],
+                        distances[at(row, column - 1)],
+                    ) + 1,
+                );
+                if row > 1
+                    && column > 1
+                    && needle[row - 1] == haystack[column - 2]
+                    && needle[row - 2] == haystack[column - 1]
+                {
+                    distance = usize::min(distance, distances[at(row - 2, column - 2)] + 1);
+                }
+                distances[at(row, column)] = distance;
+            }
+        }
+
+        let (end_column, typos) = (1..columns)
+            .map(|column| (column, distances[at(rows - 1, column)]))
+            .min_by_key(|(_, distance)| *distance)?;
+
+        if typos > max_typos {
+            return None;
+        }
+
+        // Walk the alignment back to find which haystack characters matched.
+        let mut positions = Vec::with_capacity(needle.len());
+        let (mut row, mut column) = (rows - 1, end_column);
+        while row > 0 && column > 0 {
+            let distance = distances[at(row, column)];
+            if needle[row - 1] == haystack[column - 1]
+                && distance == distances[at(row - 1, column - 1)]
+            {
+                positions.push(column - 1);
+                row -= 1;
+                column -= 1;
+            } else if distance == distances[at(row - 1, column - 1)] + 1 {
+                row -= 1;
+                column -= 1;
+            } else if row > 1
+                && column > 1
+                && needle[row - 1] == haystack[column - 2]
+                && needle[row - 2] == haystack[column - 1]
+                && distance == distances[at(row - 2, column - 2)] + 1
+            {
+                positions.push(column - 1);
+                positions.push(column - 2);
+                row -= 2;
+                column -= 2;
+            } else if distance == distances[at(row - 1, column)] + 1 {
+                row -= 1;
+            } else {
+                column -= 1;
+            }
+        }
+        positions.reverse();
+
+        Some(FuzzyMatch {
+            score: TYPO_SCORE - TYPO_PENALTY * typos as i64 + position_score(haystack, &positions),
+            positions,
+        })
+    }
+}
+
+fn is_word_start(haystack: &[char], index: usize) -> bool {
+    index == 0 || !haystack[index - 1].is_alphanumeric()
+}
+
+fn position_score(haystack: &[char], positions: &[usize]) -> i64 {
+    let mut score = 0;
+    for (index, position) in positions.iter().enumerate() {
+        if is_word_start(haystack, *position) {
+            score += WORD_START_BONUS;
+        }
+        if index > 0 {
+            let gap = position - positions[index - 1] - 1;
+            if gap == 0 {
+                score += CONSECUTIVE_BONUS;
+            } else {
+                score -= GAP_PENALTY * gap as i64;
+            }
+        }
+    }
+    score - positions.first().copied().unwrap_or_default() as i64
+}
+
+// Whether two search keys likely name the same show: the same once spacing
+// and punctuation are dropped, or a typo or so apart, as many as a search
+// term of that length tolerates.
+pub fn similar(left: &str, right: &str) -> bool {
+    let left = left
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<Vec<_>>();
+    let right = right
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<Vec<_>>();
+    if left == right {
+        return true;
+    }
+
+    let shorter = usize::min(left.len(), right.len());
+    if shorter < MIN_TYPO_TOLERANT_LEN {
+        return false;
+    }
+    let max_typos = shorter / MIN_TYPO_TOLERANT_LEN;
+    if left.len().abs_diff(right.len()) > max_typos {
+        return false;
+    }
+    edit_distance(&left, &right) <= max_typos
+}
+
+// The optimal string alignment distance between two whole strings.
+fn edit_distance(left: &[char], right: &[char]) -> usize {
+    let columns = right.len() + 1;
+    let mut distances = (0..(left.len() + 1) * columns)
+        .map(|index| if index < columns { index } else { 0 })
+        .collect::<Vec<_>>();
+    let at = |row: usize, column: usize| row * columns + column;
+
+    for row in 1..=left.len() {
+        distances[at(row, 0)] = row;
+        for column in 1..columns {
+            let substitution = usize::from(left[row - 1] != right[column - 1]);
+            let mut distance = usize::min(
+                distances[at(row - 1, column - 1)] + substitution,
+                usize::min(
+                    distances[at(row - 1, column)],
+                    distances[at(row, column - 1)],
+                ) + 1,
+            );
+            if row > 1
+                && column > 1
+                && left[row - 1] == right[column - 2]
+                && left[row - 2] == right[column - 1]
+            {
+                distance = usize::min(distance, distances[at(row - 2, column - 2)] + 1);
+            }
+            distances[at(row, column)] = distance;
+        }
+    }
+    distances[at(left.len(), right.len())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(needle: &str, haystack: &str) -> Option<FuzzyMatch> {
+        FuzzyMatcher::new(needle).find(haystack)
+    }
+
+    fn positions(needle: &str, haystack: &str) -> Option<Vec<usize>> {
+        find(needle, haystack).map(|fuzzy_match| fuzzy_match.positions)
+    }
+
+    #[test]
+    fn matches_fall_into_score_bands() {
+        let substring = find("office", "the office").unwrap().score;
+        let subsequence = find("offce", "the office").unwrap().score;
+        let typo = find("ofifce", "the office").unwrap().score;
+        assert!(substring > subsequence, "{} {}", substring, subsequence);
+        assert!(subsequence > typo, "{} {}", subsequence, typo);
+
+        // Even a substring far into a long name beats a subsequence right
+        // at the start.
+        let late_substring = find("ark", "a very long name that ends in dark").unwrap();
+        let early_subsequence = find("ark", "a rk").unwrap();
+        assert!(late_substring.score > early_subsequence.score);
+    }
+
+    #[test]
+    fn prefers_substring_then_subsequence_then_typo() {
+        assert_eq!(positions("ice", "the office"), Some(vec![7, 8, 9]));
+        assert_eq!(positions("ofce", "the office"), Some(vec![4, 5, 8, 9]));
+        assert_eq!(
+            positions("ofifce", "the office"),
+            Some(vec![4, 5, 6, 7, 8, 9])
+        );
+    }
+
+    #[test]
+    fn short_needles_need_no_typos() {
+        assert_eq!(positions("of", "the office"), Some(vec![4, 5]));
+        // One letter short of MIN_TYPO_TOLERANT_LEN, so no typos allowed.
+        assert!(find("dra", "dark").is_none());
+        assert!(find("drak", "dark").is_some());
+
+        let empty = find("", "dark").unwrap();
+        assert_eq!(empty.score, 0);
+        assert!(empty.positions.is_empty());
+    }
+
+    #[test]
+    fn typos_are_limited_by_length() {
+        assert!(find("hosue", "house").is_some());
+        assert!(find("hsoeu", "house").is_none());
+        assert!(find("hsoeu", "a house").is_none());
+        assert!(find("brekaing bda", "breaking bad").is_some());
+    }
+
+    #[test]
+    fn typo_positions_follow_the_alignment() {
+        // A swap highlights both letters.
+        assert_eq!(positions("hosue", "house"), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(positions("drak", "dark"), Some(vec![0, 1, 2, 3]));
+        // A letter missing from the name leaves the rest highlighted.
+        assert_eq!(positions("office", "the ofice"), Some(vec![4, 5, 6, 7, 8]));
+        // An extra letter in the search term highlights nothing extra.
+        assert_eq!(positions("darkk", "dark"), Some(vec![0, 1, 2, 3]));
+        // A wrong letter isn't highlighted.
+        assert_eq!(positions("lust", "lost"), Some(vec![0, 2, 3]));
+    }
+
+    fn distance(left: &str, right: &str) -> usize {
+        edit_distance(
+            &left.chars().collect::<Vec<_>>(),
+            &right.chars().collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn edit_distance_counts_edits() {
+        assert_eq!(distance("", ""), 0);
+        assert_eq!(distance("", "abc"), 3);
+        assert_eq!(distance("abc", ""), 3);
+        assert_eq!(distance("office", "office"), 0);
+        assert_eq!(distance("office", "ofice"), 1);
+        assert_eq!(distance("lost", "lust"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn edit_distance_counts_a_swap_once() {
+        assert_eq!(distance("house", "hosue"), 1);
+        assert_eq!(distance("ab", "ba"), 1);
+    }
+
+    #[test]
+    fn spelling_variants_are_similar() {
+        assert!(similar("the office", "the office"));
+        assert!(similar("the office", "the ofice"));
+        assert!(similar("breaking bad", "breakingbad"));
+        assert!(similar("house", "hosue"));
+        assert!(similar("lost", "lust"));
+    }
+
+    #[test]
+    fn different_shows_are_not_similar() {
+        assert!(!similar("dark", "dark matter"));
+        assert!(!similar("star trek", "star wars"));
+        assert!(!similar("up", "us"));
+        assert!(!similar("lost", "last ship"));
+    }
+}
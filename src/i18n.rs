@@ -0,0 +1,281 @@
+use std::cell::RefCell;
+
+use fluent_bundle::{
+    FluentArgs, FluentBundle, FluentResource, FluentValue,
+    types::{FluentNumber, FluentNumberOptions},
+};
+use intl_memoizer::IntlLangMemoizer;
+use unic_langid::LanguageIdentifier;
+
+// The languages with a catalog under locales/.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Language {
+    // Whichever of the others LANG asks for.
+    System,
+    English,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 3] = [Language::System, Language::English, Language::German];
+
+    // Each language goes by its own name, so it can be found again from any
+    // other.
+    pub fn name(self) -> String {
+        match self {
+            Language::System => tr("language-system"),
+            Language::English => "English".to_owned(),
+            Language::German => "Deutsch".to_owned(),
+        }
+    }
+
+    pub fn setting_name(self) -> &'static str {
+        match self {
+            Language::System => "system",
+            Language::English => "en-US",
+            Language::German => "de",
+        }
+    }
+
+    pub fn from_setting(setting: &str) -> Option<Language> {
+        Language::ALL
+            .into_iter()
+            .find(|language| language.setting_name() == setting)
+    }
+
+    fn resolve(self) -> Language {
+        if self != Language::System {
+            return self;
+        }
+
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|locale| !locale.is_empty())
+            .unwrap_or_default();
+        match locale.split(['_', '.', '-']).next() {
+            Some("de") => Language::German,
+            _ => Language::English,
+        }
+    }
+
+    fn catalog(self) -> &'static str {
+        match self {
+            Language::German => include_str!("../locales/de/vcc.ftl"),
+            _ => include_str!("../locales/en-US/vcc.ftl"),
+        }
+    }
+
+    // The thousands separator and decimal mark.
+    fn separators(self) -> (char, char) {
+        match self {
+            Language::German => ('.', ','),
+            _ => (',', '.'),
+        }
+    }
+
+    fn bundle(self) -> FluentBundle<FluentResource> {
+        let identifier = self
+            .setting_name()
+            .parse::<LanguageIdentifier>()
+            .expect("Unable to parse the language identifier.");
+        let resource = FluentResource::try_new(self.catalog().to_owned())
+            .expect("Unable to parse the translation catalog.");
+
+        let mut bundle = FluentBundle::new(vec![identifier]);
+        // egui would draw the isolation marks around arguments as boxes.
+        bundle.set_use_isolating(false);
+        bundle.set_formatter(Some(match self {
+            Language::German => format_german,
+            _ => format_english,
+        }));
+        bundle
+            .add_resource(resource)
+            .expect("Unable to add the translation catalog.");
+        bundle
+    }
+}
+
+struct Localizer {
+    // Never System.
+    language: Language,
+    bundle: FluentBundle<FluentResource>,
+    // Messages missing from a translation are shown in English.
+    fallback: FluentBundle<FluentResource>,
+}
+
+impl Localizer {
+    fn new(language: Language) -> Self {
+        let language = language.resolve();
+        Localizer {
+            language,
+            bundle: language.bundle(),
+            fallback: Language::English.bundle(),
+        }
+    }
+
+    fn format(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        for bundle in [&self.bundle, &self.fallback] {
+            let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else {
+                continue;
+            };
+            let mut errors = Vec::new();
+            return bundle
+                .format_pattern(pattern, args, &mut errors)
+                .into_owned();
+        }
+        // Better the id than nothing at all.
+        id.to_owned()
+    }
+}
+
+// The ui is only ever drawn from the one thread.
+thread_local! {
+    static LOCALIZER: RefCell<Localizer> = RefCell::new(Localizer::new(Language::System));
+}
+
+pub fn set_language(language: Language) {
+    LOCALIZER.with_borrow_mut(|localizer| {
+        if localizer.language != language.resolve() {
+            *localizer = Localizer::new(language);
+        }
+    });
+}
+
+pub fn tr(id: &str) -> String {
+    LOCALIZER.with_borrow(|localizer| localizer.format(id, None))
+}
+
+pub fn tr_args<'a>(id: &str, args: impl IntoIterator<Item = (&'a str, FluentValue<'a>)>) -> String {
+    let args = args.into_iter().collect::<FluentArgs>();
+    LOCALIZER.with_borrow(|localizer| localizer.format(id, Some(&args)))
+}
+
+// A number shown with a fixed number of decimals, such as an average.
+pub fn decimal(value: f64, fraction_digits: usize) -> FluentValue<'static> {
+    FluentValue::Number(FluentNumber::new(
+        value,
+        FluentNumberOptions {
+            minimum_fraction_digits: Some(fraction_digits),
+            maximum_fraction_digits: Some(fraction_digits),
+            ..Default::default()
+        },
+    ))
+}
+
+pub fn format_number(value: i64) -> String {
+    let separators = LOCALIZER.with_borrow(|localizer| localizer.language.separators());
+    grouped_number(value as f64, 0, separators)
+}
+
+// A day given as YYYY-MM-DD, such as the start of a week.
+pub fn format_date(date: &str) -> String {
+    let mut parts = date.split('-');
+    let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next()) else {
+        return date.to_owned();
+    };
+    tr_args(
+        "date-day",
+        [
+            ("year", year.into()),
+            ("month", month_name(month).into()),
+            ("day", day.trim_start_matches('0').into()),
+        ],
+    )
+}
+
+// A month given as YYYY-MM.
+pub fn format_month(month: &str) -> String {
+    let Some((year, month)) = month.split_once('-') else {
+        return month.to_owned();
+    };
+    tr_args(
+        "date-month",
+        [("year", year.into()), ("month", month_name(month).into())],
+    )
+}
+
+fn month_name(month: &str) -> String {
+    tr(&format!("month-{}", month.trim_start_matches('0')))
+}
+
+fn format_english(value: &FluentValue, _: &IntlLangMemoizer) -> Option<String> {
+    format_value(value, Language::English.separators())
+}
+
+fn format_german(value: &FluentValue, _: &IntlLangMemoizer) -> Option<String> {
+    format_value(value, Language::German.separators())
+}
+
+fn format_value(value: &FluentValue, separators: (char, char)) -> Option<String> {
+    let FluentValue::Number(number) = value else {
+        return None;
+    };
+    let fraction_digits = number
+        .options
+        .maximum_fraction_digits
+        .or(number.options.minimum_fraction_digits)
+        .unwrap_or(0);
+    Some(grouped_number(number.value, fraction_digits, separators))
+}
+
+fn grouped_number(
+    value: f64,
+    fraction_digits: usize,
+    (thousands, decimal): (char, char),
+) -> String {
+    let formatted = format!("{:.*}", fraction_digits, value.abs());
+    let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+
+    let mut number = String::new();
+    if value < 0f64 && formatted.chars().any(|c| c != '0' && c != '.') {
+        number.push('-');
+    }
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index).is_multiple_of(3) {
+            number.push(thousands);
+        }
+        number.push(digit);
+    }
+    if !fraction.is_empty() {
+        number.push(decimal);
+        number.push_str(fraction);
+    }
+    number
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENGLISH: (char, char) = (',', '.');
+    const GERMAN: (char, char) = ('.', ',');
+
+    #[test]
+    fn groups_thousands() {
+        assert_eq!(grouped_number(0f64, 0, ENGLISH), "0");
+        assert_eq!(grouped_number(999f64, 0, ENGLISH), "999");
+        assert_eq!(grouped_number(1000f64, 0, ENGLISH), "1,000");
+        assert_eq!(grouped_number(123456f64, 0, ENGLISH), "123,456");
+        assert_eq!(grouped_number(1234567f64, 0, ENGLISH), "1,234,567");
+        assert_eq!(grouped_number(1234567f64, 0, GERMAN), "1.234.567");
+    }
+
+    #[test]
+    fn rounds_to_the_fraction_digits() {
+        assert_eq!(grouped_number(7.25f64, 1, ENGLISH), "7.2");
+        assert_eq!(grouped_number(7.26f64, 1, ENGLISH), "7.3");
+        assert_eq!(grouped_number(1234.5f64, 2, GERMAN), "1.234,50");
+        assert_eq!(grouped_number(999.96f64, 1, ENGLISH), "1,000.0");
+        assert_eq!(grouped_number(2.5f64, 0, ENGLISH), "2");
+    }
+
+    #[test]
+    fn negative_numbers_keep_their_sign() {
+        assert_eq!(grouped_number(-1234f64, 0, ENGLISH), "-1,234");
+        assert_eq!(grouped_number(-0.5f64, 1, GERMAN), "-0,5");
+        // Nothing left to be negative once rounded.
+        assert_eq!(grouped_number(-0.04f64, 1, ENGLISH), "0.0");
+        assert_eq!(grouped_number(-0f64, 0, ENGLISH), "0");
+    }
+}
@@ -0,0 +1,566 @@
+use eframe::egui::{self, Key, KeyboardShortcut, Modifiers};
+
+use crate::i18n::{tr, tr_args};
+
+// Everything the keyboard can do without reaching for the mouse.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Action {
+    PreviousRow,
+    NextRow,
+    FewerEpisodes,
+    MoreEpisodes,
+    PreviousSeason,
+    NextSeason,
+    MarkWatching,
+    MarkPlanToWatch,
+    MarkCompleted,
+    RaisePriority,
+    LowerPriority,
+    ShowDetails,
+    DeleteShow,
+    RemoveFilter,
+    PreviousPage,
+    NextPage,
+    FirstPage,
+    LastPage,
+    FocusSearch,
+    FocusAdd,
+    CheatSheet,
+    CommandPalette,
+    EditKeymap,
+    ShowStatistics,
+    ShowSettings,
+    ResetLayout,
+}
+
+impl Action {
+    pub const ALL: [Action; 26] = [
+        Action::PreviousRow,
+        Action::NextRow,
+        Action::FewerEpisodes,
+        Action::MoreEpisodes,
+        Action::PreviousSeason,
+        Action::NextSeason,
+        Action::MarkWatching,
+        Action::MarkPlanToWatch,
+        Action::MarkCompleted,
+        Action::RaisePriority,
+        Action::LowerPriority,
+        Action::ShowDetails,
+        Action::DeleteShow,
+        Action::RemoveFilter,
+        Action::PreviousPage,
+        Action::NextPage,
+        Action::FirstPage,
+        Action::LastPage,
+        Action::FocusSearch,
+        Action::FocusAdd,
+        Action::CheatSheet,
+        Action::CommandPalette,
+        Action::EditKeymap,
+        Action::ShowStatistics,
+        Action::ShowSettings,
+        Action::ResetLayout,
+    ];
+
+    pub fn description(self) -> String {
+        tr(&format!("action-{}", self.config_name().replace('_', "-")))
+    }
+
+    // The action's name in the keymap file.
+    pub fn config_name(self) -> &'static str {
+        match self {
+            Action::PreviousRow => "previous_row",
+            Action::NextRow => "next_row",
+            Action::FewerEpisodes => "fewer_episodes",
+            Action::MoreEpisodes => "more_episodes",
+            Action::PreviousSeason => "previous_season",
+            Action::NextSeason => "next_season",
+            Action::MarkWatching => "mark_watching",
+            Action::MarkPlanToWatch => "mark_plan_to_watch",
+            Action::MarkCompleted => "mark_completed",
+            Action::RaisePriority => "raise_priority",
+            Action::LowerPriority => "lower_priority",
+            Action::ShowDetails => "show_details",
+            Action::DeleteShow => "delete_show",
+            Action::RemoveFilter => "remove_filter",
+            Action::PreviousPage => "previous_page",
+            Action::NextPage => "next_page",
+            Action::FirstPage => "first_page",
+            Action::LastPage => "last_page",
+            Action::FocusSearch => "focus_search",
+            Action::FocusAdd => "focus_add",
+            Action::CheatSheet => "cheat_sheet",
+            Action::CommandPalette => "command_palette",
+            Action::EditKeymap => "edit_keymap",
+            Action::ShowStatistics => "show_statistics",
+            Action::ShowSettings => "show_settings",
+            Action::ResetLayout => "reset_layout",
+        }
+    }
+
+    // Acts on the focused widget rather than the focused row, so it's
+    // checked where that widget is drawn.
+    pub fn on_focused_widget(self) -> bool {
+        matches!(self, Action::RemoveFilter)
+    }
+
+    pub fn default_shortcuts(self) -> Vec<KeyboardShortcut> {
+        let key = |logical_key| KeyboardShortcut::new(Modifiers::NONE, logical_key);
+        let command = |logical_key| KeyboardShortcut::new(Modifiers::COMMAND, logical_key);
+
+        match self {
+            Action::PreviousRow => vec![key(Key::ArrowUp), key(Key::K)],
+            Action::NextRow => vec![key(Key::ArrowDown), key(Key::J)],
+            Action::FewerEpisodes => vec![key(Key::Minus)],
+            Action::MoreEpisodes => vec![key(Key::Plus), key(Key::Equals)],
+            Action::PreviousSeason => vec![key(Key::OpenBracket)],
+            Action::NextSeason => vec![key(Key::CloseBracket)],
+            Action::MarkWatching => vec![key(Key::Num1)],
+            Action::MarkPlanToWatch => vec![key(Key::Num2)],
+            Action::MarkCompleted => vec![key(Key::Num3)],
+            Action::RaisePriority => vec![command(Key::ArrowUp)],
+            Action::LowerPriority => vec![command(Key::ArrowDown)],
+            Action::ShowDetails => vec![key(Key::Enter)],
+            Action::DeleteShow => vec![key(Key::Delete)],
+            Action::RemoveFilter => vec![key(Key::Delete)],
+            Action::PreviousPage => vec![key(Key::PageUp)],
+            Action::NextPage => vec![key(Key::PageDown)],
+            Action::FirstPage => vec![key(Key::Home)],
+            Action::LastPage => vec![key(Key::End)],
+            Action::FocusSearch => vec![command(Key::F), key(Key::Slash)],
+            Action::FocusAdd => vec![command(Key::N)],
+            Action::CheatSheet => vec![key(Key::Questionmark), key(Key::F1)],
+            Action::CommandPalette => vec![command(Key::P), command(Key::K)],
+            Action::ShowSettings => vec![command(Key::Comma)],
+            Action::EditKeymap | Action::ShowStatistics | Action::ResetLayout => Vec::new(),
+        }
+    }
+}
+
+// Why a chord such as "Ctrl+Foo" couldn't be read.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ShortcutError {
+    UnknownModifier { modifier: String, chord: String },
+    MissingKey(String),
+    UnknownKey(String),
+}
+
+impl ShortcutError {
+    pub fn message(&self) -> String {
+        match self {
+            ShortcutError::UnknownModifier { modifier, chord } => tr_args(
+                "keymap-error-unknown-modifier",
+                [
+                    ("modifier", modifier.as_str().into()),
+                    ("chord", chord.as_str().into()),
+                ],
+            ),
+            ShortcutError::MissingKey(chord) => tr_args(
+                "keymap-error-missing-key",
+                [("chord", chord.as_str().into())],
+            ),
+            ShortcutError::UnknownKey(key) => {
+                tr_args("keymap-error-unknown-key", [("key", key.as_str().into())])
+            }
+        }
+    }
+}
+
+// A line of keymap.conf that couldn't be understood, by its line number.
+#[derive(Clone, PartialEq, Debug)]
+pub enum KeymapProblem {
+    ExpectedBinding(usize),
+    UnknownAction(usize, String),
+    BadShortcut(usize, ShortcutError),
+}
+
+impl KeymapProblem {
+    pub fn message(&self) -> String {
+        match self {
+            KeymapProblem::ExpectedBinding(line) => tr_args(
+                "keymap-problem-expected-binding",
+                [("line", (*line).into())],
+            ),
+            KeymapProblem::UnknownAction(line, action) => tr_args(
+                "keymap-problem-unknown-action",
+                [("line", (*line).into()), ("action", action.as_str().into())],
+            ),
+            KeymapProblem::BadShortcut(line, error) => tr_args(
+                "keymap-problem-bad-shortcut",
+                [("line", (*line).into()), ("error", error.message().into())],
+            ),
+        }
+    }
+}
+
+// Parse a chord such as "Ctrl+Shift+F". Ctrl stands for Cmd on macOS, and
+// the key comes last, so "Ctrl++" is Ctrl and the plus key.
+pub fn parse_shortcut(text: &str) -> Result<KeyboardShortcut, ShortcutError> {
+    let text = text.trim();
+    let (modifier_names, key_name) = match text.strip_suffix('+') {
+        Some(rest) if rest.is_empty() || rest.ends_with('+') => (rest, "+"),
+        _ => text.rsplit_once('+').unwrap_or(("", text)),
+    };
+
+    let mut modifiers = Modifiers::NONE;
+    for modifier_name in modifier_names.split('+').filter(|name| !name.is_empty()) {
+        modifiers |= match modifier_name.trim().to_lowercase().as_str() {
+            "ctrl" | "control" | "cmd" | "command" => Modifiers::COMMAND,
+            "alt" | "option" => Modifiers::ALT,
+            "shift" => Modifiers::SHIFT,
+            _ => {
+                return Err(ShortcutError::UnknownModifier {
+                    modifier: modifier_name.to_owned(),
+                    chord: text.to_owned(),
+                });
+            }
+        };
+    }
+
+    let key_name = key_name.trim();
+    if key_name.is_empty() {
+        return Err(ShortcutError::MissingKey(text.to_owned()));
+    }
+    let logical_key =
+        Key::from_name(key_name).ok_or_else(|| ShortcutError::UnknownKey(key_name.to_owned()))?;
+
+    Ok(KeyboardShortcut::new(modifiers, logical_key))
+}
+
+// A comma separated list of chords, where an empty list leaves the action
+// unbound.
+pub fn parse_shortcuts(text: &str) -> Result<Vec<KeyboardShortcut>, ShortcutError> {
+    text.split(',')
+        .filter(|chord| !chord.trim().is_empty())
+        .map(parse_shortcut)
+        .collect()
+}
+
+// The inverse of parse_shortcuts.
+pub fn shortcuts_config_text(shortcuts: &[KeyboardShortcut]) -> String {
+    shortcuts
+        .iter()
+        .map(|shortcut| {
+            let mut chord = String::new();
+            if shortcut.modifiers.command || shortcut.modifiers.ctrl {
+                chord.push_str("Ctrl+");
+            }
+            if shortcut.modifiers.alt {
+                chord.push_str("Alt+");
+            }
+            if shortcut.modifiers.shift {
+                chord.push_str("Shift+");
+            }
+            chord.push_str(shortcut.logical_key.name());
+            chord
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Only shortcuts held with Ctrl, Cmd or Alt reach past a focused text box,
+// everything else is left to be typed.
+fn usable_while_typing(shortcut: &KeyboardShortcut) -> bool {
+    shortcut.modifiers.command || shortcut.modifiers.ctrl || shortcut.modifiers.alt
+}
+
+// Enter, Space and Delete on their own belong to a focused button or
+// checkbox rather than to the focused row.
+fn usable_on_focused_widget(shortcut: &KeyboardShortcut) -> bool {
+    usable_while_typing(shortcut)
+        || !matches!(shortcut.logical_key, Key::Enter | Key::Space | Key::Delete)
+}
+
+// Whether both shortcuts can fire at the same time. An action on the focused
+// widget only competes with shortcuts that aren't left to that widget.
+fn competes(
+    action: Action,
+    shortcut: &KeyboardShortcut,
+    other_action: Action,
+    other_shortcut: &KeyboardShortcut,
+) -> bool {
+    match (action.on_focused_widget(), other_action.on_focused_widget()) {
+        (true, false) => usable_on_focused_widget(other_shortcut),
+        (false, true) => usable_on_focused_widget(shortcut),
+        _ => true,
+    }
+}
+
+// The chord that triggers both shortcuts, if there is one.
+fn overlap<'a>(
+    shortcut: &'a KeyboardShortcut,
+    other: &'a KeyboardShortcut,
+) -> Option<&'a KeyboardShortcut> {
+    if shortcut.logical_key != other.logical_key {
+        return None;
+    }
+    if shortcut.modifiers.matches_logically(other.modifiers) {
+        Some(shortcut)
+    } else if other.modifiers.matches_logically(shortcut.modifiers) {
+        Some(other)
+    } else {
+        None
+    }
+}
+
+fn keymap_path() -> String {
+    let home_path = match std::env::var("HOME") {
+        Ok(val) => val,
+        _ => panic!("Unable to read HOME environment variable."),
+    };
+
+    format!("{}/.config/vcc/keymap.conf", home_path)
+}
+
+// Which chords trigger which action, read from
+// ~/.config/vcc/keymap.conf. Each line of the file binds an action to a
+// comma separated list of chords, like `next_row = Down, J`, and actions
+// missing from it keep their default shortcuts.
+#[derive(Clone, PartialEq)]
+pub struct Keymap {
+    // Indexed by Action.
+    bindings: Vec<Vec<KeyboardShortcut>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Keymap {
+            bindings: Action::ALL.map(Action::default_shortcuts).to_vec(),
+        }
+    }
+}
+
+impl Keymap {
+    // The keymap, along with whatever in the file couldn't be understood.
+    pub fn load() -> (Keymap, Vec<KeymapProblem>) {
+        let mut keymap = Keymap::default();
+        let mut problems = Vec::new();
+
+        let Ok(config) = std::fs::read_to_string(keymap_path()) else {
+            return (keymap, problems);
+        };
+
+        for (line_index, line) in config.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((action_name, chords)) = line.split_once('=') else {
+                problems.push(KeymapProblem::ExpectedBinding(line_index + 1));
+                continue;
+            };
+
+            let action_name = action_name.trim();
+            let Some(action) = Action::ALL
+                .into_iter()
+                .find(|action| action.config_name() == action_name)
+            else {
+                problems.push(KeymapProblem::UnknownAction(
+                    line_index + 1,
+                    action_name.to_owned(),
+                ));
+                continue;
+            };
+
+            match parse_shortcuts(chords) {
+                Ok(shortcuts) => keymap.set_shortcuts(action, shortcuts),
+                Err(error) => problems.push(KeymapProblem::BadShortcut(line_index + 1, error)),
+            }
+        }
+
+        (keymap, problems)
+    }
+
+    // Saving can fail on a read-only config directory, which is shown
+    // rather than taken down with the rest of vcc.
+    pub fn save(&self) -> Result<(), String> {
+        let path = keymap_path();
+        if let Some(directory) = std::path::Path::new(&path).parent() {
+            std::fs::create_dir_all(directory).map_err(|error| error.to_string())?;
+        }
+
+        let mut config = String::from("# action = comma separated keys, such as Ctrl+Shift+F\n");
+        for action in Action::ALL {
+            config.push_str(&format!(
+                "{} = {}\n",
+                action.config_name(),
+                shortcuts_config_text(self.shortcuts(action))
+            ));
+        }
+        std::fs::write(path, config).map_err(|error| error.to_string())
+    }
+
+    pub fn shortcuts(&self, action: Action) -> &[KeyboardShortcut] {
+        &self.bindings[action as usize]
+    }
+
+    pub fn set_shortcuts(&mut self, action: Action, shortcuts: Vec<KeyboardShortcut>) {
+        self.bindings[action as usize] = shortcuts;
+    }
+
+    // Chords that trigger more than one action, of which only the first
+    // checked ever fires. Extra Shift and Alt are ignored when matching a
+    // shortcut, so Ctrl+Shift+F also triggers one bound to Ctrl+F.
+    pub fn conflicts(&self, ctx: &egui::Context) -> Vec<String> {
+        let mut conflicts = Vec::new();
+        for (index, action) in Action::ALL.into_iter().enumerate() {
+            for other_action in &Action::ALL[index + 1..] {
+                for shortcut in self.shortcuts(action) {
+                    for other_shortcut in self.shortcuts(*other_action) {
+                        let Some(chord) = overlap(shortcut, other_shortcut)
+                            .filter(|_| competes(action, shortcut, *other_action, other_shortcut))
+                        else {
+                            continue;
+                        };
+                        conflicts.push(tr_args(
+                            "keymap-conflict",
+                            [
+                                ("shortcut", ctx.format_shortcut(chord).into()),
+                                ("action", action.description().into()),
+                                ("other_action", other_action.description().into()),
+                            ],
+                        ));
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+
+    // Whether one of the action's shortcuts was pressed this frame. The key
+    // press is consumed, so that no widget reacts to it as well.
+    pub fn pressed(&self, ctx: &egui::Context, action: Action) -> bool {
+        let typing = ctx.text_edit_focused();
+        let widget_focused = !typing && ctx.memory(|m| m.focused()).is_some();
+        ctx.input_mut(|i| {
+            self.shortcuts(action)
+                .iter()
+                .filter(|shortcut| !typing || usable_while_typing(shortcut))
+                .filter(|shortcut| {
+                    !widget_focused
+                        || action.on_focused_widget()
+                        || usable_on_focused_widget(shortcut)
+                })
+                .any(|shortcut| i.consume_shortcut(shortcut))
+        })
+    }
+
+    pub fn shortcuts_text(&self, ctx: &egui::Context, action: Action) -> String {
+        self.shortcuts(action)
+            .iter()
+            .map(|shortcut| ctx.format_shortcut(shortcut))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::{self, Language};
+
+    #[test]
+    fn parses_chords() {
+        assert_eq!(
+            parse_shortcut("Ctrl+Shift+F"),
+            Ok(KeyboardShortcut::new(
+                Modifiers::COMMAND | Modifiers::SHIFT,
+                Key::F
+            ))
+        );
+        assert_eq!(
+            parse_shortcut(" alt + Down "),
+            Ok(KeyboardShortcut::new(Modifiers::ALT, Key::ArrowDown))
+        );
+        assert_eq!(
+            parse_shortcut("J"),
+            Ok(KeyboardShortcut::new(Modifiers::NONE, Key::J))
+        );
+    }
+
+    #[test]
+    fn parses_plus() {
+        assert_eq!(
+            parse_shortcut("+"),
+            Ok(KeyboardShortcut::new(Modifiers::NONE, Key::Plus))
+        );
+        assert_eq!(
+            parse_shortcut("Ctrl++"),
+            Ok(KeyboardShortcut::new(Modifiers::COMMAND, Key::Plus))
+        );
+    }
+
+    #[test]
+    fn rejects_bad_chords() {
+        assert_eq!(
+            parse_shortcut("Hyper+J"),
+            Err(ShortcutError::UnknownModifier {
+                modifier: "Hyper".to_owned(),
+                chord: "Hyper+J".to_owned(),
+            })
+        );
+        assert_eq!(
+            parse_shortcut("Ctrl+"),
+            Err(ShortcutError::MissingKey("Ctrl+".to_owned()))
+        );
+        assert_eq!(
+            parse_shortcut("Ctrl+Foo"),
+            Err(ShortcutError::UnknownKey("Foo".to_owned()))
+        );
+    }
+
+    #[test]
+    fn problems_are_translated() {
+        let problem = KeymapProblem::BadShortcut(3, ShortcutError::UnknownKey("Foo".to_owned()));
+        i18n::set_language(Language::English);
+        assert_eq!(problem.message(), "keymap.conf line 3: unknown key `Foo`");
+        i18n::set_language(Language::German);
+        assert_eq!(
+            problem.message(),
+            "keymap.conf Zeile 3: unbekannte Taste `Foo`"
+        );
+    }
+
+    #[test]
+    fn empty_list_unbinds() {
+        assert_eq!(parse_shortcuts(""), Ok(Vec::new()));
+        assert_eq!(parse_shortcuts(" , "), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn default_shortcuts_round_trip() {
+        for action in Action::ALL {
+            let shortcuts = action.default_shortcuts();
+            let text = shortcuts_config_text(&shortcuts);
+            assert_eq!(parse_shortcuts(&text), Ok(shortcuts), "{}", text);
+        }
+    }
+
+    #[test]
+    fn default_shortcuts_do_not_conflict() {
+        assert!(
+            Keymap::default()
+                .conflicts(&egui::Context::default())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn extra_modifiers_conflict() {
+        let mut keymap = Keymap::default();
+        keymap.set_shortcuts(Action::CheatSheet, parse_shortcuts("Ctrl+Shift+F").unwrap());
+        assert_eq!(keymap.conflicts(&egui::Context::default()).len(), 1);
+
+        keymap.set_shortcuts(Action::CheatSheet, parse_shortcuts("Shift+F").unwrap());
+        assert!(keymap.conflicts(&egui::Context::default()).is_empty());
+    }
+
+    #[test]
+    fn focused_widget_actions_conflict_with_what_reaches_widgets() {
+        let mut keymap = Keymap::default();
+        keymap.set_shortcuts(Action::RemoveFilter, parse_shortcuts("J").unwrap());
+        assert_eq!(keymap.conflicts(&egui::Context::default()).len(), 1);
+    }
+}
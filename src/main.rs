@@ -1,11 +1,20 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
 
+mod export;
+mod fuzzy;
+mod i18n;
+mod keymap;
+mod query;
+mod search_key;
+mod settings;
 mod show;
 mod shows_db;
 mod shows_view;
+mod sorting;
+mod stats;
 mod ui_painter;
 
-use ui_painter::Vcc;
+use ui_painter::{DEFAULT_WINDOW_SIZE, Vcc};
 
 use eframe::egui::{self};
 
@@ -14,8 +23,8 @@ struct VccApplication {
 }
 
 impl VccApplication {
-    fn new() -> Self {
-        let vcc = Vcc::new();
+    fn new(cc: &eframe::CreationContext) -> Self {
+        let vcc = Vcc::new(&cc.egui_ctx, cc.storage);
         Self { vcc }
     }
 }
@@ -24,17 +33,29 @@ impl eframe::App for VccApplication {
     fn ui(&mut self, ui: &mut egui::Ui, _frame: &mut eframe::Frame) {
         self.vcc.paint_ui(ui);
     }
+
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        self.vcc.save(storage);
+    }
 }
 
 pub fn main() -> eframe::Result {
+    // `vcc stats [profile]` prints the library statistics instead of opening
+    // the window.
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() == Some("stats") {
+        stats::print_statistics(args.next());
+        return Ok(());
+    }
+
     let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default(),
+        viewport: egui::ViewportBuilder::default().with_inner_size(DEFAULT_WINDOW_SIZE),
         ..Default::default()
     };
 
     eframe::run_native(
         "vcc",
         options,
-        Box::new(|_| Ok(Box::new(VccApplication::new()))),
+        Box::new(|cc| Ok(Box::new(VccApplication::new(cc)))),
     )
 }
@@ -0,0 +1,588 @@
+use std::{cmp::Ordering, iter::Peekable, str::CharIndices};
+
+use crate::{
+    i18n::{tr, tr_args},
+    search_key::search_key,
+    show::{DisplayShow, ShowCategory, split_list, unix_now},
+};
+
+// A filter typed into the search box, such as
+// `cat:watching season>2 -"star" (ep<3 OR ep>=10)`.
+//
+// Terms separated by whitespace must all hold, `OR` (or `|`) joins
+// alternatives, a leading `-` (or `!`, `NOT`) negates a term, parentheses
+// group, and quotes keep a phrase together.
+pub struct Query {
+    expr: Expr,
+    plain: bool,
+}
+
+// Every spelling of the fields, in lowercase. Any other word followed by an
+// operator is just text, like the "Trek:" of "Star Trek: Discovery".
+const FIELDS: &[(&str, Field)] = &[
+    ("name", Field::Name),
+    ("alias", Field::Alias),
+    ("aliases", Field::Alias),
+    ("tag", Field::Tag),
+    ("tags", Field::Tag),
+    ("notes", Field::Notes),
+    ("note", Field::Notes),
+    ("cat", Field::Category),
+    ("category", Field::Category),
+    ("season", Field::Number(NumberField::Season)),
+    ("s", Field::Number(NumberField::Season)),
+    ("episodes", Field::Number(NumberField::Episodes)),
+    ("episode", Field::Number(NumberField::Episodes)),
+    ("ep", Field::Number(NumberField::Episodes)),
+    ("e", Field::Number(NumberField::Episodes)),
+    ("rating", Field::Number(NumberField::Rating)),
+    ("r", Field::Number(NumberField::Rating)),
+    ("idle", Field::Number(NumberField::Idle)),
+];
+
+fn field(word: &str) -> Option<Field> {
+    let word = word.to_lowercase();
+    FIELDS
+        .iter()
+        .find(|(name, _)| *name == word)
+        .map(|(_, field)| *field)
+}
+
+#[derive(Copy, Clone)]
+enum Field {
+    Name,
+    Alias,
+    Tag,
+    Notes,
+    Category,
+    Number(NumberField),
+}
+
+// Why the search box doesn't hold a query, such as `season>` or `(dark`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum QueryError {
+    UnmatchedClose,
+    MissingClose,
+    MissingQuote,
+    ExpectedTerm,
+    UnknownOperator(String),
+    MissingValue(String),
+    // The field as typed.
+    NotComparable(String),
+    UnknownCategory(String),
+    // The field as typed, and its value.
+    NotANumber(String, String),
+}
+
+impl QueryError {
+    pub fn message(&self) -> String {
+        match self {
+            QueryError::UnmatchedClose => tr("query-error-unmatched-close"),
+            QueryError::MissingClose => tr("query-error-missing-close"),
+            QueryError::MissingQuote => tr("query-error-missing-quote"),
+            QueryError::ExpectedTerm => tr("query-error-expected-term"),
+            QueryError::UnknownOperator(operator) => tr_args(
+                "query-error-unknown-operator",
+                [("operator", operator.as_str().into())],
+            ),
+            QueryError::MissingValue(field) => tr_args(
+                "query-error-missing-value",
+                [("field", field.as_str().into())],
+            ),
+            QueryError::NotComparable(field) => tr_args(
+                "query-error-not-comparable",
+                [("field", field.as_str().into())],
+            ),
+            QueryError::UnknownCategory(category) => tr_args(
+                "query-error-unknown-category",
+                [("category", category.as_str().into())],
+            ),
+            QueryError::NotANumber(field, value) => tr_args(
+                "query-error-not-a-number",
+                [
+                    ("field", field.as_str().into()),
+                    ("value", value.as_str().into()),
+                ],
+            ),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Comparison {
+    Contains,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+impl Comparison {
+    fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Comparison::Contains | Comparison::Equal => ordering.is_eq(),
+            Comparison::NotEqual => ordering.is_ne(),
+            Comparison::Less => ordering.is_lt(),
+            Comparison::LessEqual => ordering.is_le(),
+            Comparison::Greater => ordering.is_gt(),
+            Comparison::GreaterEqual => ordering.is_ge(),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum NumberField {
+    Season,
+    Episodes,
+    Rating,
+    // Days since the progress last changed.
+    Idle,
+}
+
+impl NumberField {
+    fn value(self, show: &DisplayShow) -> Option<i64> {
+        const SECONDS_PER_DAY: i64 = 60 * 60 * 24;
+
+        match self {
+            NumberField::Season => show.season_number.parse().ok(),
+            NumberField::Episodes => show.episodes_seen.parse().ok(),
+            NumberField::Rating => show.rating(),
+            NumberField::Idle => Some((unix_now() - show.last_updated) / SECONDS_PER_DAY),
+        }
+    }
+}
+
+enum Expr {
+    Text(String),
+    Name(Comparison, String),
+    Alias(String),
+    Tag(String),
+    Notes(String),
+    Category(Comparison, ShowCategory),
+    Number(NumberField, Comparison, i64),
+    Not(Box<Expr>),
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+}
+
+impl Expr {
+    fn matches(&self, show: &DisplayShow) -> bool {
+        match self {
+            Expr::Text(text) => show.search_key().as_str().contains(text.as_str()),
+            Expr::Name(Comparison::Contains, text) => {
+                show.search_key().as_str().contains(text.as_str())
+            }
+            Expr::Name(comparison, text) => {
+                comparison.holds(show.search_key().as_str().cmp(text.as_str()))
+            }
+            Expr::Alias(text) => split_list(&show.details.aliases)
+                .any(|alias| search_key(alias).contains(text.as_str())),
+            Expr::Tag(tag) => {
+                split_list(&show.details.tags).any(|show_tag| search_key(show_tag) == *tag)
+            }
+            Expr::Notes(text) => search_key(&show.details.notes).contains(text.as_str()),
+            Expr::Category(comparison, category) => {
+                comparison.holds((show.category as i64).cmp(&(*category as i64)))
+            }
+            Expr::Number(field, comparison, value) => field
+                .value(show)
+                .is_some_and(|number| comparison.holds(number.cmp(value))),
+            Expr::Not(expr) => !expr.matches(show),
+            Expr::And(exprs) => exprs.iter().all(|expr| expr.matches(show)),
+            Expr::Or(exprs) => exprs.iter().any(|expr| expr.matches(show)),
+        }
+    }
+
+    fn filters_category(&self) -> bool {
+        match self {
+            Expr::Category(..) => true,
+            Expr::Not(expr) => expr.filters_category(),
+            Expr::And(exprs) | Expr::Or(exprs) => exprs.iter().any(Expr::filters_category),
+            _ => false,
+        }
+    }
+}
+
+impl Query {
+    pub fn parse(input: &str) -> Result<Query, QueryError> {
+        let tokens = tokenize(input)?;
+        let plain = tokens.iter().all(|token| {
+            matches!(
+                token,
+                Token::Term {
+                    field: None,
+                    quoted: false,
+                    ..
+                }
+            )
+        });
+
+        let mut parser = Parser {
+            tokens: tokens.into_iter().peekable(),
+        };
+        let expr = parser.parse_or()?;
+        if parser.tokens.next().is_some() {
+            return Err(QueryError::UnmatchedClose);
+        }
+
+        Ok(Query { expr, plain })
+    }
+
+    // Nothing but bare words, which are better served by the fuzzy name search.
+    pub fn is_plain(&self) -> bool {
+        self.plain
+    }
+
+    // Whether the query picks its own categories, rather than filtering
+    // within the selected one.
+    pub fn filters_category(&self) -> bool {
+        self.expr.filters_category()
+    }
+
+    pub fn matches(&self, show: &DisplayShow) -> bool {
+        self.expr.matches(show)
+    }
+}
+
+enum Token {
+    Term {
+        // The field as typed, which errors point out, and what it stands for.
+        field: Option<(String, Field, Comparison)>,
+        value: String,
+        quoted: bool,
+    },
+    Not,
+    Or,
+    Open,
+    Close,
+}
+
+fn is_operator(c: char) -> bool {
+    matches!(c, ':' | '=' | '!' | '<' | '>')
+}
+
+fn is_word_end(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '(' | ')' | '"')
+}
+
+fn read_phrase(chars: &mut Peekable<CharIndices>) -> Result<String, QueryError> {
+    let mut phrase = String::new();
+    for (_, c) in chars.by_ref() {
+        if c == '"' {
+            return Ok(phrase);
+        }
+        phrase.push(c);
+    }
+    Err(QueryError::MissingQuote)
+}
+
+fn read_word(chars: &mut Peekable<CharIndices>, word: &mut String) {
+    while let Some((_, c)) = chars.next_if(|(_, c)| !is_word_end(*c)) {
+        word.push(c);
+    }
+}
+
+fn read_operator(chars: &mut Peekable<CharIndices>) -> Result<Comparison, QueryError> {
+    let mut operator = String::new();
+    while let Some((_, c)) = chars.next_if(|(_, c)| is_operator(*c)) {
+        operator.push(c);
+    }
+
+    match operator.as_str() {
+        ":" => Ok(Comparison::Contains),
+        "=" | "==" => Ok(Comparison::Equal),
+        "!=" => Ok(Comparison::NotEqual),
+        "<" => Ok(Comparison::Less),
+        "<=" => Ok(Comparison::LessEqual),
+        ">" => Ok(Comparison::Greater),
+        ">=" => Ok(Comparison::GreaterEqual),
+        _ => Err(QueryError::UnknownOperator(operator)),
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((_, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => (),
+            '(' => tokens.push(Token::Open),
+            ')' => tokens.push(Token::Close),
+            '|' => tokens.push(Token::Or),
+            '"' => tokens.push(Token::Term {
+                field: None,
+                value: read_phrase(&mut chars)?,
+                quoted: true,
+            }),
+            '-' | '!' if chars.peek().is_some_and(|(_, next)| !next.is_whitespace()) => {
+                tokens.push(Token::Not)
+            }
+            c => {
+                let mut word = String::from(c);
+                while let Some((_, c)) = chars.next_if(|(_, c)| c.is_alphanumeric() || *c == '_') {
+                    word.push(c);
+                }
+
+                let typed_field = field(&word)
+                    .filter(|_| chars.peek().is_some_and(|(_, next)| is_operator(*next)));
+                if let Some(typed_field) = typed_field {
+                    let comparison = read_operator(&mut chars)?;
+                    let (value, quoted) = if chars.next_if(|(_, c)| *c == '"').is_some() {
+                        (read_phrase(&mut chars)?, true)
+                    } else {
+                        let mut value = String::new();
+                        read_word(&mut chars, &mut value);
+                        (value, false)
+                    };
+
+                    if value.is_empty() {
+                        return Err(QueryError::MissingValue(word));
+                    }
+
+                    tokens.push(Token::Term {
+                        field: Some((word, typed_field, comparison)),
+                        value,
+                        quoted,
+                    });
+                    continue;
+                }
+
+                read_word(&mut chars, &mut word);
+                tokens.push(match word.as_str() {
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    // Terms are joined with AND already.
+                    "AND" => continue,
+                    _ => Token::Term {
+                        field: None,
+                        value: word,
+                        quoted: false,
+                    },
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<I: Iterator<Item = Token>> {
+    tokens: Peekable<I>,
+}
+
+impl<I: Iterator<Item = Token>> Parser<I> {
+    fn parse_or(&mut self) -> Result<Expr, QueryError> {
+        let mut alternatives = vec![self.parse_and()?];
+        while self
+            .tokens
+            .next_if(|token| matches!(token, Token::Or))
+            .is_some()
+        {
+            alternatives.push(self.parse_and()?);
+        }
+
+        Ok(match alternatives.len() {
+            1 => alternatives.pop().unwrap(),
+            _ => Expr::Or(alternatives),
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, QueryError> {
+        let mut terms = Vec::new();
+        while self
+            .tokens
+            .peek()
+            .is_some_and(|token| !matches!(token, Token::Or | Token::Close))
+        {
+            terms.push(self.parse_unary()?);
+        }
+
+        match terms.len() {
+            0 => Err(QueryError::ExpectedTerm),
+            1 => Ok(terms.pop().unwrap()),
+            _ => Ok(Expr::And(terms)),
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, QueryError> {
+        match self.tokens.next() {
+            Some(Token::Not) => Ok(Expr::Not(Box::new(self.parse_unary()?))),
+            Some(Token::Open) => {
+                let expr = self.parse_or()?;
+                match self.tokens.next() {
+                    Some(Token::Close) => Ok(expr),
+                    _ => Err(QueryError::MissingClose),
+                }
+            }
+            Some(Token::Term { field, value, .. }) => compile_term(field, value),
+            _ => Err(QueryError::ExpectedTerm),
+        }
+    }
+}
+
+fn compile_term(
+    field: Option<(String, Field, Comparison)>,
+    value: String,
+) -> Result<Expr, QueryError> {
+    let Some((field_name, field, comparison)) = field else {
+        return Ok(Expr::Text(search_key(&value)));
+    };
+    let value = value.to_lowercase();
+
+    match field {
+        Field::Name => match comparison {
+            Comparison::Contains | Comparison::Equal | Comparison::NotEqual => {
+                Ok(Expr::Name(comparison, search_key(&value)))
+            }
+            _ => Err(QueryError::NotComparable(field_name)),
+        },
+        Field::Alias | Field::Tag | Field::Notes => {
+            let value = search_key(&value);
+            let expr = match field {
+                Field::Alias => Expr::Alias(value),
+                Field::Tag => Expr::Tag(value),
+                _ => Expr::Notes(value),
+            };
+            match comparison {
+                Comparison::Contains | Comparison::Equal => Ok(expr),
+                Comparison::NotEqual => Ok(Expr::Not(Box::new(expr))),
+                _ => Err(QueryError::NotComparable(field_name)),
+            }
+        }
+        Field::Category => {
+            let category = match value.as_str() {
+                "watching" | "w" => ShowCategory::Watching,
+                "plantowatch" | "plan" | "ptw" | "p" => ShowCategory::PlanToWatch,
+                "completed" | "complete" | "done" | "c" => ShowCategory::Completed,
+                _ => return Err(QueryError::UnknownCategory(value)),
+            };
+            match comparison {
+                Comparison::Contains | Comparison::Equal | Comparison::NotEqual => {
+                    Ok(Expr::Category(comparison, category))
+                }
+                _ => Err(QueryError::NotComparable(field_name)),
+            }
+        }
+        Field::Number(number_field) => {
+            let number = value
+                .parse::<i64>()
+                .map_err(|_| QueryError::NotANumber(field_name, value))?;
+            Ok(Expr::Number(number_field, comparison, number))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::{self, Language};
+
+    fn show(name: &str, season: i64, episodes: i64, category: ShowCategory) -> DisplayShow {
+        DisplayShow::new_numeric(
+            name.to_owned(),
+            season,
+            episodes,
+            category,
+            &Default::default(),
+        )
+    }
+
+    fn matches(query: &str, show: &DisplayShow) -> bool {
+        Query::parse(query)
+            .unwrap_or_else(|error| panic!("{}: {:?}", query, error))
+            .matches(show)
+    }
+
+    #[test]
+    fn plain_words_are_plain() {
+        assert!(Query::parse("breaking bad").unwrap().is_plain());
+        assert!(!Query::parse("\"breaking bad\"").unwrap().is_plain());
+        assert!(!Query::parse("season>2").unwrap().is_plain());
+    }
+
+    #[test]
+    fn unknown_fields_are_text() {
+        let query = Query::parse("Star Trek: Discovery").unwrap();
+        assert!(query.is_plain());
+        assert!(query.matches(&show("Star Trek: Discovery", 1, 0, ShowCategory::Watching)));
+        assert!(Query::parse("foo!=bar").unwrap().is_plain());
+    }
+
+    #[test]
+    fn fields_compare() {
+        let watching = show("Dark", 3, 4, ShowCategory::Watching);
+        assert!(matches("season>2", &watching));
+        assert!(matches("season>=3 ep<5", &watching));
+        assert!(!matches("season<3", &watching));
+        assert!(matches("cat:watching", &watching));
+        assert!(matches("cat!=completed", &watching));
+        assert!(!matches("cat:done", &watching));
+        assert!(matches("NAME=dark", &watching));
+        assert!(matches("name:\"da\"", &watching));
+    }
+
+    #[test]
+    fn operators_combine() {
+        let dark = show("Dark", 3, 4, ShowCategory::Watching);
+        assert!(matches("ep<3 OR ep>=4", &dark));
+        assert!(matches("ep<3 | ep>=4", &dark));
+        assert!(!matches("-dark", &dark));
+        assert!(!matches("NOT dark", &dark));
+        assert!(matches("!(ep<3 OR season>5) dark", &dark));
+        assert!(matches("dark AND season=3", &dark));
+    }
+
+    #[test]
+    fn details_match() {
+        let mut show = show("Dark", 1, 1, ShowCategory::Completed);
+        show.details.aliases = "Dunkel, Black".to_owned();
+        show.details.tags = "Mystery, German".to_owned();
+        show.details.notes = "Watch the Café scene".to_owned();
+        assert!(matches("alias:dunk", &show));
+        assert!(matches("tag=german", &show));
+        assert!(!matches("tag=germ", &show));
+        assert!(matches("tag!=comedy", &show));
+        assert!(matches("notes:cafe", &show));
+    }
+
+    #[test]
+    fn errors() {
+        let error = |query| Query::parse(query).err().unwrap();
+        assert_eq!(error("(dark"), QueryError::MissingClose);
+        assert_eq!(error("dark)"), QueryError::UnmatchedClose);
+        assert_eq!(error("\"dark"), QueryError::MissingQuote);
+        assert_eq!(
+            error("season:"),
+            QueryError::MissingValue("season".to_owned())
+        );
+        assert_eq!(
+            error("season=x"),
+            QueryError::NotANumber("season".to_owned(), "x".to_owned())
+        );
+        assert_eq!(
+            error("cat:later"),
+            QueryError::UnknownCategory("later".to_owned())
+        );
+        assert_eq!(
+            error("name<dark"),
+            QueryError::NotComparable("name".to_owned())
+        );
+        assert_eq!(
+            error("season=>2"),
+            QueryError::UnknownOperator("=>".to_owned())
+        );
+        assert_eq!(error("dark OR"), QueryError::ExpectedTerm);
+    }
+
+    #[test]
+    fn errors_are_translated() {
+        let error = QueryError::NotANumber("season".to_owned(), "x".to_owned());
+        i18n::set_language(Language::English);
+        assert_eq!(error.message(), "`season` expects a number, not `x`");
+        i18n::set_language(Language::German);
+        assert_eq!(error.message(), "`season` erwartet eine Zahl, nicht `x`");
+    }
+}
@@ -0,0 +1,80 @@
+use caseless::Caseless;
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+// Fold a single character the way search keys are built: compatibility
+// decomposition, then dropping diacritics, then full case folding.
+// "É" becomes "e", "ﬁ" becomes "fi" and "ß" becomes "ss".
+fn fold_char(c: char) -> impl Iterator<Item = char> {
+    std::iter::once(c)
+        .nfkd()
+        .filter(|c| !is_combining_mark(*c))
+        .default_case_fold()
+}
+
+// The form both show names and search terms are compared in, so that
+// "pokemon" finds "Pokémon" and "strasse" finds "Straße".
+pub fn search_key(text: &str) -> String {
+    if text.is_ascii() {
+        return text.to_ascii_lowercase();
+    }
+    text.chars().flat_map(fold_char).collect()
+}
+
+// Map character indices into `search_key(text)` back onto the character
+// of `text` each one was folded from.
+pub fn original_positions(text: &str, key_positions: &[usize]) -> Vec<usize> {
+    if text.is_ascii() {
+        return key_positions.to_owned();
+    }
+
+    let origins = text
+        .chars()
+        .enumerate()
+        .flat_map(|(index, c)| fold_char(c).map(move |_| index))
+        .collect::<Vec<_>>();
+
+    let mut positions = key_positions
+        .iter()
+        .filter_map(|position| origins.get(*position).copied())
+        .collect::<Vec<_>>();
+    positions.dedup();
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folds_case_and_accents() {
+        assert_eq!(search_key("Breaking Bad"), "breaking bad");
+        assert_eq!(search_key("Pokémon"), "pokemon");
+        assert_eq!(search_key("ÉLITE"), "elite");
+        assert_eq!(search_key("Ñandú"), "nandu");
+    }
+
+    #[test]
+    fn expands_compatibility_characters() {
+        assert_eq!(search_key("Straße"), "strasse");
+        assert_eq!(search_key("ﬁrefly"), "firefly");
+        assert_eq!(search_key("Ｄａｒｋ"), "dark");
+        assert_eq!(search_key("Season ²"), "season 2");
+    }
+
+    #[test]
+    fn ascii_positions_are_unchanged() {
+        assert_eq!(original_positions("Dark", &[1, 2]), vec![1, 2]);
+    }
+
+    #[test]
+    fn positions_map_back_through_expansions() {
+        // "strasse": the two s of "ß" both come from index 4.
+        assert_eq!(original_positions("Straße", &[3, 4, 5, 6]), vec![3, 4, 5]);
+        // "firefly": f and i come from the ligature at index 0.
+        assert_eq!(original_positions("ﬁrefly", &[1, 2]), vec![0, 1]);
+        // Dropped accents leave one character per letter.
+        assert_eq!(original_positions("Pokémon", &[3, 4]), vec![3, 4]);
+        // Positions past the end of the key are ignored.
+        assert_eq!(original_positions("Straße", &[6, 7]), vec![5]);
+    }
+}
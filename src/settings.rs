@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use eframe::egui::{self, FontData, FontDefinitions, FontFamily};
+
+use crate::i18n::{self, Language, tr, tr_args};
+
+// Kept by eframe between sessions. The theme and UI scale are part of
+// egui's own memory, which eframe keeps as well.
+const LANGUAGE_KEY: &str = "vcc.language";
+const DENSITY_KEY: &str = "vcc.density";
+const FONT_KEY: &str = "vcc.font";
+
+const CUSTOM_FONT_NAME: &str = "custom";
+
+// How tightly the rows are packed.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Density {
+    Compact,
+    Standard,
+    Comfortable,
+}
+
+impl Density {
+    pub const ALL: [Density; 3] = [Density::Compact, Density::Standard, Density::Comfortable];
+
+    pub fn name(self) -> String {
+        match self {
+            Density::Compact => tr("density-compact"),
+            Density::Standard => tr("density-standard"),
+            Density::Comfortable => tr("density-comfortable"),
+        }
+    }
+
+    fn setting_name(self) -> &'static str {
+        match self {
+            Density::Compact => "compact",
+            Density::Standard => "standard",
+            Density::Comfortable => "comfortable",
+        }
+    }
+
+    fn from_setting(setting: &str) -> Option<Density> {
+        Density::ALL
+            .into_iter()
+            .find(|density| density.setting_name() == setting)
+    }
+
+    // Only the vertical spacing changes, starting from egui's.
+    fn apply(self, style: &mut egui::Style) {
+        let spacing = egui::style::Spacing::default();
+        let (item_spacing, button_padding, row_height) = match self {
+            Density::Compact => (1f32, 0f32, 16f32),
+            Density::Standard => (
+                spacing.item_spacing.y,
+                spacing.button_padding.y,
+                spacing.interact_size.y,
+            ),
+            Density::Comfortable => (6f32, 4f32, 26f32),
+        };
+        style.spacing.item_spacing.y = item_spacing;
+        style.spacing.button_padding.y = button_padding;
+        style.spacing.interact_size.y = row_height;
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub enum Font {
+    Default,
+    Monospace,
+    // A TrueType or OpenType font file.
+    File(String),
+}
+
+impl Font {
+    pub fn name(&self) -> String {
+        match self {
+            Font::Default => tr("font-default"),
+            Font::Monospace => tr("font-monospace"),
+            Font::File(_) => tr("font-file"),
+        }
+    }
+
+    fn to_setting(&self) -> String {
+        match self {
+            Font::Default => "default".to_owned(),
+            Font::Monospace => "monospace".to_owned(),
+            Font::File(path) => format!("file:{}", path),
+        }
+    }
+
+    fn from_setting(setting: &str) -> Option<Font> {
+        match setting {
+            "default" => Some(Font::Default),
+            "monospace" => Some(Font::Monospace),
+            _ => setting
+                .strip_prefix("file:")
+                .map(|path| Font::File(path.to_owned())),
+        }
+    }
+
+    // egui's fonts, with the chosen one put first for text. The built in
+    // fonts stay behind it for the characters it lacks.
+    fn definitions(&self) -> Result<FontDefinitions, String> {
+        let mut definitions = FontDefinitions::default();
+        let font_name = match self {
+            Font::Default => return Ok(definitions),
+            Font::File(path) if path.is_empty() => return Ok(definitions),
+            Font::Monospace => definitions.families[&FontFamily::Monospace][0].to_owned(),
+            Font::File(path) => {
+                let font_error = |error: String| {
+                    tr_args(
+                        "font-error",
+                        [("path", path.as_str().into()), ("error", error.into())],
+                    )
+                };
+                let font = std::fs::read(path).map_err(|error| font_error(error.to_string()))?;
+                skrifa::FontRef::from_index(&font, 0)
+                    .map_err(|error| font_error(error.to_string()))?;
+                definitions.font_data.insert(
+                    CUSTOM_FONT_NAME.to_owned(),
+                    Arc::new(FontData::from_owned(font)),
+                );
+                CUSTOM_FONT_NAME.to_owned()
+            }
+        };
+
+        definitions
+            .families
+            .entry(FontFamily::Proportional)
+            .or_default()
+            .insert(0, font_name);
+        Ok(definitions)
+    }
+}
+
+// How the app looks, beyond the theme and scale.
+#[derive(Clone, PartialEq)]
+pub struct Settings {
+    pub language: Language,
+    pub density: Density,
+    pub font: Font,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            language: Language::System,
+            density: Density::Standard,
+            font: Font::Default,
+        }
+    }
+}
+
+impl Settings {
+    pub fn load(storage: Option<&dyn eframe::Storage>) -> Settings {
+        let Some(storage) = storage else {
+            return Settings::default();
+        };
+
+        Settings {
+            language: storage
+                .get_string(LANGUAGE_KEY)
+                .and_then(|setting| Language::from_setting(&setting))
+                .unwrap_or(Language::System),
+            density: storage
+                .get_string(DENSITY_KEY)
+                .and_then(|setting| Density::from_setting(&setting))
+                .unwrap_or(Density::Standard),
+            font: storage
+                .get_string(FONT_KEY)
+                .and_then(|setting| Font::from_setting(&setting))
+                .unwrap_or(Font::Default),
+        }
+    }
+
+    pub fn save(&self, storage: &mut dyn eframe::Storage) {
+        storage.set_string(LANGUAGE_KEY, self.language.setting_name().to_owned());
+        storage.set_string(DENSITY_KEY, self.density.setting_name().to_owned());
+        storage.set_string(FONT_KEY, self.font.to_setting());
+    }
+
+    // Style the context, keeping the built in fonts when the font file won't
+    // load.
+    pub fn apply(&self, ctx: &egui::Context) -> Result<(), String> {
+        i18n::set_language(self.language);
+
+        let density = self.density;
+        ctx.all_styles_mut(|style| density.apply(style));
+
+        match self.font.definitions() {
+            Ok(definitions) => {
+                ctx.set_fonts(definitions);
+                Ok(())
+            }
+            Err(error) => {
+                ctx.set_fonts(FontDefinitions::default());
+                Err(error)
+            }
+        }
+    }
+}
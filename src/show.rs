@@ -1,7 +1,16 @@
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use strumbra::SharedString;
 
+use crate::{
+    i18n::tr,
+    search_key::search_key,
+    sorting::{SortOptions, compare_sort_keys},
+};
+
 #[derive(Copy, Clone, PartialEq, Debug, Default)]
 pub enum ShowCategory {
     Watching = 0,
@@ -10,6 +19,22 @@ pub enum ShowCategory {
     Completed = 2,
 }
 
+impl ShowCategory {
+    pub const ALL: [ShowCategory; 3] = [
+        ShowCategory::Watching,
+        ShowCategory::PlanToWatch,
+        ShowCategory::Completed,
+    ];
+
+    pub fn name(self) -> String {
+        match self {
+            ShowCategory::Watching => tr("category-watching"),
+            ShowCategory::PlanToWatch => tr("category-plan-to-watch"),
+            ShowCategory::Completed => tr("category-completed"),
+        }
+    }
+}
+
 impl TryFrom<i64> for ShowCategory {
     type Error = ();
 
@@ -23,12 +48,51 @@ impl TryFrom<i64> for ShowCategory {
     }
 }
 
+pub const MAX_RATING: i64 = 10;
+
+// Ratings are optional, but when given must be a whole number out of MAX_RATING.
+pub fn valid_rating(rating: &str) -> bool {
+    rating.is_empty()
+        || rating
+            .parse::<i64>()
+            .is_ok_and(|rating| (0..=MAX_RATING).contains(&rating))
+}
+
+pub fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or_default()
+}
+
 #[derive(Clone)]
 pub struct Show<S> {
     pub name: S,
     pub season_number: String,
     pub episodes_seen: String,
     pub category: ShowCategory,
+    // Out of MAX_RATING, empty while unrated.
+    pub rating: String,
+    // Seconds since the unix epoch, 0 if the progress was never changed.
+    pub last_updated: i64,
+    // Where a Plan to Watch show comes in the profile's priority order.
+    // Shows never put in order come after the rest, alphabetically.
+    pub position: Option<i64>,
+    pub details: ShowDetails,
+}
+
+// What every profile shares about a show, aliases and tags are comma separated.
+#[derive(Clone, Default, PartialEq)]
+pub struct ShowDetails {
+    pub aliases: String,
+    pub tags: String,
+    pub notes: String,
+}
+
+pub fn split_list(list: &str) -> impl Iterator<Item = &str> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
 }
 
 pub type AdderShow = Show<String>;
@@ -43,6 +107,8 @@ impl AdderShow {
         self.season_number = AdderShow::default_numeric_string();
         self.episodes_seen = AdderShow::default_numeric_string();
         self.category = ShowCategory::PlanToWatch;
+        self.rating.clear();
+        self.details = Default::default();
     }
 }
 
@@ -53,20 +119,29 @@ impl Default for AdderShow {
             season_number: AdderShow::default_numeric_string(),
             episodes_seen: AdderShow::default_numeric_string(),
             category: Default::default(),
+            rating: Default::default(),
+            last_updated: Default::default(),
+            position: None,
+            details: Default::default(),
         }
     }
 }
 
+// Show names are ordered by a key built from the sort options. SQLite orders
+// by the same key through the SHOW_NAME collation, so the loaded vectors come
+// back in `Ord` order and can be binary searched.
 #[derive(Clone)]
 pub struct SearchableName {
-    lower_name: SharedString,
+    sort_key: SharedString,
+    search_key: SharedString,
     name: SharedString,
 }
 
 impl SearchableName {
-    pub fn new(name: String) -> Self {
+    pub fn new(name: String, sort_options: &SortOptions) -> Self {
         SearchableName {
-            lower_name: SharedString::try_from(name.to_lowercase()).unwrap(),
+            sort_key: SharedString::try_from(sort_options.sort_key(&name)).unwrap(),
+            search_key: SharedString::try_from(search_key(&name)).unwrap(),
             name: SharedString::try_from(name).unwrap(),
         }
     }
@@ -80,12 +155,17 @@ impl DisplayShow {
         season_number: String,
         episodes_seen: String,
         category: ShowCategory,
+        sort_options: &SortOptions,
     ) -> DisplayShow {
         Show {
-            name: SearchableName::new(name),
+            name: SearchableName::new(name, sort_options),
             season_number,
             episodes_seen,
             category,
+            rating: Default::default(),
+            last_updated: Default::default(),
+            position: None,
+            details: Default::default(),
         }
     }
 
@@ -94,12 +174,14 @@ impl DisplayShow {
         season_number: i64,
         episodes_seen: i64,
         category: ShowCategory,
+        sort_options: &SortOptions,
     ) -> DisplayShow {
         Show::new(
             name,
             format!("{}", season_number),
             format!("{}", episodes_seen),
             category,
+            sort_options,
         )
     }
 
@@ -107,14 +189,22 @@ impl DisplayShow {
         &self.name.name
     }
 
-    pub fn lower_name(&self) -> &SharedString {
-        &self.name.lower_name
+    pub fn rating(&self) -> Option<i64> {
+        self.rating.parse().ok()
+    }
+
+    pub fn sort_key(&self) -> &SharedString {
+        &self.name.sort_key
+    }
+
+    pub fn search_key(&self) -> &SharedString {
+        &self.name.search_key
     }
 }
 
 impl Ord for DisplayShow {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.lower_name().cmp(other.lower_name())
+        compare_sort_keys(self.sort_key(), self.name(), other.sort_key(), other.name())
     }
 }
 
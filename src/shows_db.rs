@@ -1,17 +1,127 @@
-use crate::show::*;
+use crate::{show::*, sorting::SortOptions};
+
+// Each entry upgrades the schema from version `index` to `index + 1`,
+// tracked through SQLite's user_version pragma.
+const MIGRATIONS: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS Shows (name TEXT, season_number INTEGER, episodes_seen INTEGER, category INTEGER);
+     CREATE INDEX IF NOT EXISTS idx_shows_name ON Shows (name);
+     CREATE INDEX IF NOT EXISTS idx_shows_category ON Shows (category);",
+    // Split the shared show catalog from the per profile progress.
+    "CREATE TABLE Profiles (id INTEGER PRIMARY KEY, name TEXT NOT NULL UNIQUE);
+     INSERT INTO Profiles (id, name) VALUES (1, 'Default');
+     CREATE TABLE ShowProgress (profile_id INTEGER NOT NULL, name TEXT NOT NULL, season_number INTEGER, episodes_seen INTEGER, category INTEGER, PRIMARY KEY (profile_id, name));
+     INSERT OR IGNORE INTO ShowProgress SELECT 1, name, season_number, episodes_seen, category FROM Shows;
+     CREATE TABLE ShowCatalog (name TEXT PRIMARY KEY);
+     INSERT OR IGNORE INTO ShowCatalog SELECT name FROM Shows;
+     DROP TABLE Shows;
+     ALTER TABLE ShowCatalog RENAME TO Shows;",
+    "CREATE TABLE Settings (key TEXT PRIMARY KEY, value TEXT NOT NULL);",
+    "ALTER TABLE ShowProgress ADD COLUMN rating INTEGER;
+     ALTER TABLE ShowProgress ADD COLUMN last_updated INTEGER;",
+    // Full text index over the catalog. It reads its text from the catalog's
+    // rows, and is kept in sync by rowid rather than by scanning it for a
+    // name.
+    "ALTER TABLE Shows ADD COLUMN aliases TEXT NOT NULL DEFAULT '';
+     ALTER TABLE Shows ADD COLUMN tags TEXT NOT NULL DEFAULT '';
+     ALTER TABLE Shows ADD COLUMN notes TEXT NOT NULL DEFAULT '';
+     CREATE VIRTUAL TABLE ShowsSearch USING fts5 (name, aliases, tags, notes, content = 'Shows', content_rowid = 'rowid', tokenize = 'unicode61 remove_diacritics 2');
+     INSERT INTO ShowsSearch (ShowsSearch) VALUES ('rebuild');
+     CREATE TRIGGER shows_search_insert AFTER INSERT ON Shows BEGIN
+         INSERT INTO ShowsSearch (rowid, name, aliases, tags, notes) VALUES (new.rowid, new.name, new.aliases, new.tags, new.notes);
+     END;
+     CREATE TRIGGER shows_search_delete AFTER DELETE ON Shows BEGIN
+         INSERT INTO ShowsSearch (ShowsSearch, rowid, name, aliases, tags, notes) VALUES ('delete', old.rowid, old.name, old.aliases, old.tags, old.notes);
+     END;
+     CREATE TRIGGER shows_search_update AFTER UPDATE ON Shows BEGIN
+         INSERT INTO ShowsSearch (ShowsSearch, rowid, name, aliases, tags, notes) VALUES ('delete', old.rowid, old.name, old.aliases, old.tags, old.notes);
+         INSERT INTO ShowsSearch (rowid, name, aliases, tags, notes) VALUES (new.rowid, new.name, new.aliases, new.tags, new.notes);
+     END;",
+    "CREATE TABLE SmartFilters (id INTEGER PRIMARY KEY, name TEXT NOT NULL, search_term TEXT NOT NULL, category INTEGER NOT NULL);",
+    // Episodes watched over time, recorded as progress moves forward. A later
+    // season counts every episode seen of it, and going back a season isn't
+    // watching anything.
+    "CREATE TABLE ProgressEvents (profile_id INTEGER NOT NULL, name TEXT NOT NULL, episodes INTEGER NOT NULL, recorded_at INTEGER NOT NULL);
+     CREATE INDEX idx_progress_events_profile ON ProgressEvents (profile_id, recorded_at);
+     CREATE TRIGGER progress_events_update AFTER UPDATE OF season_number, episodes_seen ON ShowProgress
+     WHEN (new.season_number > old.season_number AND new.episodes_seen > 0)
+         OR (new.season_number = old.season_number AND new.episodes_seen > old.episodes_seen)
+     BEGIN
+         INSERT INTO ProgressEvents (profile_id, name, episodes, recorded_at)
+         VALUES (new.profile_id, new.name, new.episodes_seen - CASE WHEN new.season_number = old.season_number THEN old.episodes_seen ELSE 0 END, new.last_updated);
+     END;",
+    // Each profile's priority order of its Plan to Watch shows.
+    "ALTER TABLE ShowProgress ADD COLUMN position INTEGER;",
+    // Every change to a show, for its activity log. Changes to the catalog
+    // are shared by every profile, and have no profile of their own. A show
+    // only given a place in the priority order hasn't changed category, so
+    // there's nothing to log.
+    "CREATE TABLE ShowActivity (profile_id INTEGER, name TEXT NOT NULL, field TEXT NOT NULL, old_value TEXT, new_value TEXT, recorded_at INTEGER NOT NULL);
+     CREATE INDEX idx_show_activity_name ON ShowActivity (name, recorded_at);
+     CREATE TRIGGER show_activity_insert AFTER INSERT ON ShowProgress WHEN new.position IS NULL BEGIN
+         INSERT INTO ShowActivity (profile_id, name, field, old_value, new_value, recorded_at)
+         VALUES (new.profile_id, new.name, 'category', NULL, new.category, CAST(strftime('%s', 'now') AS INTEGER));
+     END;
+     CREATE TRIGGER show_activity_progress AFTER UPDATE OF season_number, episodes_seen, category, rating ON ShowProgress BEGIN
+         INSERT INTO ShowActivity (profile_id, name, field, old_value, new_value, recorded_at)
+         SELECT new.profile_id, new.name, field, old_value, new_value, CAST(strftime('%s', 'now') AS INTEGER)
+         FROM (SELECT 'category' AS field, old.category AS old_value, new.category AS new_value
+               UNION ALL SELECT 'season', old.season_number, new.season_number
+               UNION ALL SELECT 'episodes', old.episodes_seen, new.episodes_seen
+               UNION ALL SELECT 'rating', old.rating, new.rating)
+         WHERE old_value IS NOT new_value;
+     END;
+     CREATE TRIGGER show_activity_details AFTER UPDATE OF aliases, tags, notes ON Shows BEGIN
+         INSERT INTO ShowActivity (profile_id, name, field, old_value, new_value, recorded_at)
+         SELECT NULL, new.name, field, old_value, new_value, CAST(strftime('%s', 'now') AS INTEGER)
+         FROM (SELECT 'aliases' AS field, old.aliases AS old_value, new.aliases AS new_value
+               UNION ALL SELECT 'tags', old.tags, new.tags
+               UNION ALL SELECT 'notes', old.notes, new.notes)
+         WHERE old_value IS NOT new_value;
+     END;
+     CREATE TRIGGER show_activity_delete AFTER DELETE ON Shows BEGIN
+         DELETE FROM ShowActivity WHERE name = old.name;
+     END;",
+];
+
+const IGNORE_ARTICLES_SETTING: &str = "sort.ignore_articles";
+const ARTICLES_SETTING: &str = "sort.articles";
+
+pub const DEFAULT_PROFILE_ID: i64 = 1;
+
+#[derive(Clone)]
+pub struct Profile {
+    pub id: i64,
+    pub name: String,
+}
+
+// A change to a show, as recorded for its activity log.
+pub struct Activity {
+    // One of category, season, episodes, rating, aliases, tags or notes.
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    // YYYY-MM-DD and HH:MM, in local time.
+    pub date: String,
+    pub time: String,
+}
+
+// A search saved under a name, to be picked like a category.
+#[derive(Clone)]
+pub struct SmartFilter {
+    pub id: i64,
+    pub name: String,
+    pub search_term: String,
+    // A UiShowCategory.
+    pub category: i64,
+}
 
 pub struct ShowsDb {
     connection: rusqlite::Connection,
+    sort_options: SortOptions,
 }
 
 impl ShowsDb {
     pub fn new() -> Self {
-        let show_schema = "CREATE TABLE IF NOT EXISTS Shows (name TEXT, season_number INTEGER, episodes_seen INTEGER, category INTEGER)";
-
-        let name_index = "CREATE INDEX IF NOT EXISTS idx_shows_name ON Shows (name)";
-
-        let category_index = "CREATE INDEX IF NOT EXISTS idx_shows_category ON Shows (category)";
-
         let home_path = match std::env::var("HOME") {
             Ok(val) => val,
             _ => panic!("Unable to read HOME environment variable."),
@@ -23,72 +133,467 @@ impl ShowsDb {
         let connection = rusqlite::Connection::open(format!("{}/shows.db", vcc_db_path)) //sqlite::open("./shows.db")
             .expect("Unable to Find show database.");
 
-        connection
-            .execute(show_schema, rusqlite::params![])
-            .expect("Unable to create initial table.");
+        let mut shows_db = ShowsDb {
+            connection,
+            sort_options: Default::default(),
+        };
+        shows_db.migrate();
+
+        let mut sort_options = SortOptions::default();
+        if let Some(ignore_articles) = shows_db.setting(IGNORE_ARTICLES_SETTING) {
+            sort_options.ignore_articles = ignore_articles == "1";
+        }
+        if let Some(articles) = shows_db.setting(ARTICLES_SETTING) {
+            sort_options.articles = SortOptions::parse_articles(&articles);
+        }
+        shows_db.register_collation(sort_options);
+
+        shows_db
+    }
+
+    fn register_collation(&mut self, sort_options: SortOptions) {
+        let collation_options = sort_options.clone();
+        self.connection
+            .create_collation("SHOW_NAME", move |left, right| {
+                collation_options.compare_names(left, right)
+            })
+            .expect("Unable to register show name collation.");
+        self.sort_options = sort_options;
+    }
+
+    pub fn sort_options(&self) -> &SortOptions {
+        &self.sort_options
+    }
+
+    // Goes up with every row written, triggers included.
+    pub fn total_changes(&self) -> u64 {
+        self.connection.total_changes()
+    }
+
+    pub fn set_sort_options(&mut self, sort_options: SortOptions) {
+        self.set_setting(
+            IGNORE_ARTICLES_SETTING,
+            if sort_options.ignore_articles {
+                "1"
+            } else {
+                "0"
+            },
+        );
+        self.set_setting(ARTICLES_SETTING, &sort_options.articles.join(","));
+        self.register_collation(sort_options);
+    }
+
+    pub fn setting(&self, key: &str) -> Option<String> {
+        let setting_query = "SELECT value FROM Settings WHERE key = ?1";
+
+        match self
+            .connection
+            .query_row(setting_query, rusqlite::params![key], |row| row.get(0))
+        {
+            Ok(value) => Some(value),
+            Err(rusqlite::Error::QueryReturnedNoRows) => None,
+            Err(error) => panic!("Unable to read setting {}: {}", key, error),
+        }
+    }
+
+    pub fn set_setting(&self, key: &str, value: &str) {
+        let set_setting_query = "INSERT INTO Settings (key, value) VALUES (?1, ?2)
+                                 ON CONFLICT (key) DO UPDATE SET value = excluded.value";
+
+        self.connection
+            .execute(set_setting_query, rusqlite::params![key, value])
+            .expect("Unable to save setting.");
+    }
+
+    fn migrate(&self) {
+        let version: i64 = self
+            .connection
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .expect("Unable to read database schema version.");
+
+        for (index, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+            let transaction = self
+                .connection
+                .unchecked_transaction()
+                .expect("Unable to begin schema migration.");
 
-        connection
-            .execute(name_index, rusqlite::params![])
-            .expect("Unable to create name index.");
+            transaction
+                .execute_batch(migration)
+                .expect("Unable to migrate database schema.");
 
-        connection
-            .execute(category_index, rusqlite::params![])
-            .expect("Unable to create category index.");
+            transaction
+                .pragma_update(None, "user_version", index as i64 + 1)
+                .expect("Unable to update database schema version.");
 
-        ShowsDb { connection }
+            transaction
+                .commit()
+                .expect("Unable to commit schema migration.");
+        }
     }
 
-    pub fn add(&self, show: &DisplayShow) {
-        let add_query = "INSERT INTO Shows(name, season_number, episodes_seen, category) VALUES (?1, ?2, ?3, ?4)";
+    pub fn profiles(&self) -> Vec<Profile> {
+        let profiles_query = "SELECT id, name FROM Profiles ORDER BY id";
 
         let mut statement = self
             .connection
-            .prepare(add_query)
-            .expect("Unable to prepare add query.");
+            .prepare(profiles_query)
+            .expect("Unable to prepare profiles query.");
 
         statement
-            .execute(rusqlite::params![
-                show.name().as_str(),
-                show.season_number,
-                show.episodes_seen,
-                show.category as i64
-            ])
-            .expect("Unable to insert show.");
+            .query_map([], |row| {
+                Ok(Profile {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                })
+            })
+            .expect("Unable to execute profiles query.")
+            .map(|result| result.expect("Unable to extract profile."))
+            .collect()
     }
 
-    pub fn remove(&self, show: &DisplayShow) {
-        let remove_query = "DELETE from Shows WHERE name = ?1";
+    pub fn add_profile(&self, name: &str) -> i64 {
+        let add_profile_query = "INSERT OR IGNORE INTO Profiles (name) VALUES (?1)";
+
+        self.connection
+            .execute(add_profile_query, rusqlite::params![name])
+            .expect("Unable to insert profile.");
+
+        self.connection
+            .query_row(
+                "SELECT id FROM Profiles WHERE name = ?1",
+                rusqlite::params![name],
+                |row| row.get(0),
+            )
+            .expect("Unable to find inserted profile.")
+    }
+
+    pub fn smart_filters(&self) -> Vec<SmartFilter> {
+        let smart_filters_query =
+            "SELECT id, name, search_term, category FROM SmartFilters ORDER BY id";
 
         let mut statement = self
             .connection
-            .prepare(remove_query)
-            .expect("Unable to prepare show delete query.");
+            .prepare(smart_filters_query)
+            .expect("Unable to prepare smart filters query.");
 
         statement
-            .execute(rusqlite::params![show.name().as_str()])
-            .expect("Unable to delete show.");
+            .query_map([], |row| {
+                Ok(SmartFilter {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    search_term: row.get(2)?,
+                    category: row.get(3)?,
+                })
+            })
+            .expect("Unable to execute smart filters query.")
+            .map(|result| result.expect("Unable to extract smart filter."))
+            .collect()
+    }
+
+    pub fn add_smart_filter(&self, name: &str, search_term: &str, category: i64) -> i64 {
+        let add_smart_filter_query =
+            "INSERT INTO SmartFilters (name, search_term, category) VALUES (?1, ?2, ?3)";
+
+        self.connection
+            .execute(
+                add_smart_filter_query,
+                rusqlite::params![name, search_term, category],
+            )
+            .expect("Unable to insert smart filter.");
+
+        self.connection.last_insert_rowid()
+    }
+
+    pub fn remove_smart_filter(&self, id: i64) {
+        let remove_smart_filter_query = "DELETE FROM SmartFilters WHERE id = ?1";
+
+        self.connection
+            .execute(remove_smart_filter_query, rusqlite::params![id])
+            .expect("Unable to delete smart filter.");
+    }
+
+    pub fn add(&self, profile_ids: &[i64], show: &DisplayShow) {
+        let add_query =
+            "INSERT OR IGNORE INTO Shows (name, aliases, tags, notes) VALUES (?1, ?2, ?3, ?4)";
+
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .expect("Unable to begin add transaction.");
+
+        transaction
+            .execute(
+                add_query,
+                rusqlite::params![
+                    show.name().as_str(),
+                    show.details.aliases,
+                    show.details.tags,
+                    show.details.notes
+                ],
+            )
+            .expect("Unable to insert show.");
+
+        ShowsDb::update_progress(&transaction, profile_ids, show);
+
+        transaction.commit().expect("Unable to commit show insert.");
     }
 
-    pub fn update(&self, show: &DisplayShow) {
-        let update_query = "UPDATE Shows SET season_number = ?1, episodes_seen = ?2, category = ?3 WHERE name = ?4";
+    // Several shows at once are changed in a single transaction, so that a
+    // bulk change either happens to all of them or to none.
+    pub fn remove(&self, shows: &[DisplayShow]) {
+        let remove_query = "DELETE FROM Shows WHERE name = ?1";
+        let remove_progress_query = "DELETE FROM ShowProgress WHERE name = ?1";
+
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .expect("Unable to begin delete transaction.");
+
+        for show in shows {
+            transaction
+                .execute(
+                    remove_progress_query,
+                    rusqlite::params![show.name().as_str()],
+                )
+                .expect("Unable to delete show progress.");
+
+            transaction
+                .execute(remove_query, rusqlite::params![show.name().as_str()])
+                .expect("Unable to delete show.");
+        }
+
+        transaction.commit().expect("Unable to commit show delete.");
+    }
+
+    pub fn update(&self, profile_ids: &[i64], shows: &[DisplayShow]) {
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .expect("Unable to begin update transaction.");
+
+        for show in shows {
+            ShowsDb::update_progress(&transaction, profile_ids, show);
+        }
+
+        transaction.commit().expect("Unable to commit show update.");
+    }
+
+    pub fn update_details(&self, shows: &[DisplayShow]) {
+        let update_details_query =
+            "UPDATE Shows SET aliases = ?1, tags = ?2, notes = ?3 WHERE name = ?4";
+
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .expect("Unable to begin details transaction.");
+
+        for show in shows {
+            transaction
+                .execute(
+                    update_details_query,
+                    rusqlite::params![
+                        show.details.aliases,
+                        show.details.tags,
+                        show.details.notes,
+                        show.name().as_str()
+                    ],
+                )
+                .expect("Unable to update show details.");
+        }
+
+        transaction
+            .commit()
+            .expect("Unable to commit show details update.");
+    }
+
+    // Names of the shows matching every word of the search term as a prefix,
+    // in any of their name, aliases, tags or notes, best match first.
+    pub fn search(&self, search_term: &str) -> Vec<String> {
+        let search_query = "SELECT name FROM ShowsSearch WHERE ShowsSearch MATCH ?1
+                            ORDER BY bm25(ShowsSearch, 10.0, 5.0, 2.0, 1.0)";
+
+        let match_expression = search_term
+            .split_whitespace()
+            .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(" ");
+        if match_expression.is_empty() {
+            return Vec::new();
+        }
 
         let mut statement = self
             .connection
+            .prepare(search_query)
+            .expect("Unable to prepare search query.");
+
+        statement
+            .query_map(rusqlite::params![match_expression], |row| row.get(0))
+            .expect("Unable to execute search query.")
+            .map(|result| result.expect("Unable to extract search result."))
+            .collect()
+    }
+
+    // Put the shows in a profile's priority order, all in one transaction. A
+    // show the profile has no progress for yet gets it along with its place.
+    pub fn set_positions(&self, profile_id: i64, shows: &[DisplayShow]) {
+        let position_query =
+            "UPDATE ShowProgress SET position = ?3 WHERE profile_id = ?1 AND name = ?2";
+        let insert_query = "INSERT INTO ShowProgress (profile_id, name, season_number, episodes_seen, category, last_updated, position) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)";
+
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .expect("Unable to begin position transaction.");
+
+        {
+            let mut statement = transaction
+                .prepare(position_query)
+                .expect("Unable to prepare position query.");
+            let mut insert_statement = transaction
+                .prepare(insert_query)
+                .expect("Unable to prepare position insert query.");
+
+            for show in shows {
+                let updated = statement
+                    .execute(rusqlite::params![
+                        profile_id,
+                        show.name().as_str(),
+                        show.position
+                    ])
+                    .expect("Unable to update show position.");
+                if updated == 0 {
+                    insert_statement
+                        .execute(rusqlite::params![
+                            profile_id,
+                            show.name().as_str(),
+                            show.season_number,
+                            show.episodes_seen,
+                            show.category as i64,
+                            show.last_updated,
+                            show.position
+                        ])
+                        .expect("Unable to insert show position.");
+                }
+            }
+        }
+
+        transaction
+            .commit()
+            .expect("Unable to commit show positions.");
+    }
+
+    // The first profile is the one making the change, the rest are watching
+    // along and share its progress, but keep their own ratings. A show
+    // changing category loses its place in the priority order.
+    fn update_progress(connection: &rusqlite::Connection, profile_ids: &[i64], show: &DisplayShow) {
+        let update_query = "INSERT INTO ShowProgress (profile_id, name, season_number, episodes_seen, category, rating, last_updated) VALUES (?1, ?2, ?3, ?4, ?5, CASE WHEN ?8 THEN ?6 END, ?7)
+                            ON CONFLICT (profile_id, name) DO UPDATE SET season_number = excluded.season_number, episodes_seen = excluded.episodes_seen, category = excluded.category, rating = CASE WHEN ?8 THEN excluded.rating ELSE rating END, last_updated = excluded.last_updated, position = CASE WHEN excluded.category = category THEN position END";
+
+        let mut statement = connection
             .prepare(update_query)
             .expect("Unable to prepare update query.");
 
+        for (index, profile_id) in profile_ids.iter().enumerate() {
+            statement
+                .execute(rusqlite::params![
+                    profile_id,
+                    show.name().as_str(),
+                    show.season_number,
+                    show.episodes_seen,
+                    show.category as i64,
+                    show.rating(),
+                    show.last_updated,
+                    index == 0
+                ])
+                .expect("Unable to update show.");
+        }
+    }
+
+    // The latest changes to a show made by a profile, or to the catalog,
+    // newest first.
+    pub fn activity(&self, profile_id: i64, name: &str, limit: i64) -> Vec<Activity> {
+        let activity_query = "SELECT field, old_value, new_value, date(recorded_at, 'unixepoch', 'localtime'), strftime('%H:%M', recorded_at, 'unixepoch', 'localtime')
+                              FROM ShowActivity WHERE name = ?1 AND (profile_id = ?2 OR profile_id IS NULL)
+                              ORDER BY recorded_at DESC, rowid DESC LIMIT ?3";
+
+        let mut statement = self
+            .connection
+            .prepare(activity_query)
+            .expect("Unable to prepare activity query.");
+
         statement
-            .execute(rusqlite::params![
-                show.season_number,
-                show.episodes_seen,
-                show.category as i64,
-                show.name().as_str()
-            ])
-            .expect("Unable to update show.");
+            .query_map(rusqlite::params![name, profile_id, limit], |row| {
+                Ok(Activity {
+                    field: row.get(0)?,
+                    old_value: row.get(1)?,
+                    new_value: row.get(2)?,
+                    date: row.get(3)?,
+                    time: row.get(4)?,
+                })
+            })
+            .expect("Unable to execute activity query.")
+            .map(|result| result.expect("Unable to extract activity."))
+            .collect()
+    }
+
+    // Episodes watched in each of the last `weeks` weeks, from Monday on,
+    // oldest first.
+    pub fn episodes_per_week(&self, profile_id: i64, weeks: i64) -> Vec<(String, i64)> {
+        let weeks_query = "WITH RECURSIVE periods (period_offset, period) AS (
+                               SELECT 0, date('now', 'localtime', 'weekday 0', '-6 days')
+                               UNION ALL
+                               SELECT period_offset + 1, date('now', 'localtime', 'weekday 0', '-6 days', '-' || ((period_offset + 1) * 7) || ' days')
+                               FROM periods WHERE period_offset + 1 < ?2
+                           )
+                           SELECT period, (SELECT COALESCE(SUM(episodes), 0) FROM ProgressEvents
+                                           WHERE profile_id = ?1 AND date(recorded_at, 'unixepoch', 'localtime', 'weekday 0', '-6 days') = period)
+                           FROM periods ORDER BY period";
+
+        self.episodes_per_period(weeks_query, profile_id, weeks)
     }
 
-    pub fn load_all_shows(&self) -> impl Iterator<Item = DisplayShow> + '_ {
-        let load_query = "SELECT * FROM Shows ORDER BY category, name COLLATE NOCASE";
+    // Episodes watched in each of the last `months` calendar months, oldest
+    // first.
+    pub fn episodes_per_month(&self, profile_id: i64, months: i64) -> Vec<(String, i64)> {
+        let months_query = "WITH RECURSIVE periods (period_offset, period) AS (
+                                SELECT 0, strftime('%Y-%m', 'now', 'localtime')
+                                UNION ALL
+                                SELECT period_offset + 1, strftime('%Y-%m', 'now', 'localtime', 'start of month', '-' || (period_offset + 1) || ' months')
+                                FROM periods WHERE period_offset + 1 < ?2
+                            )
+                            SELECT period, (SELECT COALESCE(SUM(episodes), 0) FROM ProgressEvents
+                                            WHERE profile_id = ?1 AND strftime('%Y-%m', recorded_at, 'unixepoch', 'localtime') = period)
+                            FROM periods ORDER BY period";
+
+        self.episodes_per_period(months_query, profile_id, months)
+    }
+
+    fn episodes_per_period(
+        &self,
+        query: &str,
+        profile_id: i64,
+        periods: i64,
+    ) -> Vec<(String, i64)> {
+        let mut statement = self
+            .connection
+            .prepare(query)
+            .expect("Unable to prepare progress events query.");
+
+        statement
+            .query_map(rusqlite::params![profile_id, periods], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .expect("Unable to execute progress events query.")
+            .map(|result| result.expect("Unable to extract progress events row."))
+            .collect()
+    }
+
+    pub fn load_all_shows(&self, profile_id: i64) -> impl Iterator<Item = DisplayShow> + '_ {
+        // Shows this profile has never touched fall back to the defaults of
+        // a freshly added show.
+        let load_query = "SELECT Shows.name, COALESCE(p.season_number, 0), COALESCE(p.episodes_seen, 0), COALESCE(p.category, ?2) AS category, p.rating, COALESCE(p.last_updated, 0), p.position, Shows.aliases, Shows.tags, Shows.notes
+                          FROM Shows LEFT JOIN ShowProgress p ON p.name = Shows.name AND p.profile_id = ?1
+                          ORDER BY category, Shows.name COLLATE SHOW_NAME";
 
         let mut statement = self
             .connection
@@ -96,28 +601,61 @@ impl ShowsDb {
             .expect("Unable to prepare load query.");
 
         let rows = statement
-            .query_map([], |row| {
-                Ok((
-                    row.get(0)?,
-                    row.get(1)?,
-                    row.get(2)?,
-                    row.get::<usize, i64>(3)?,
-                ))
-            })
+            .query_map(
+                rusqlite::params![profile_id, ShowCategory::default() as i64],
+                |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get::<usize, i64>(3)?,
+                        row.get::<usize, Option<i64>>(4)?,
+                        row.get(5)?,
+                        row.get(6)?,
+                        row.get(7)?,
+                        row.get(8)?,
+                        row.get(9)?,
+                    ))
+                },
+            )
             .expect("Unable to execute query.")
             .map(|result| result.expect("Unable to extract row."))
             .collect::<Vec<_>>();
 
-        rows.into_iter()
-            .map(|(name, season_number, episodes_seen, category)| {
-                DisplayShow::new_numeric(
+        rows.into_iter().map(
+            |(
+                name,
+                season_number,
+                episodes_seen,
+                category,
+                rating,
+                last_updated,
+                position,
+                aliases,
+                tags,
+                notes,
+            )| {
+                let show = DisplayShow::new_numeric(
                     name,
                     season_number,
                     episodes_seen,
                     category
                         .try_into()
                         .expect("Unable to convert numeric category to ShowCategory."),
-                )
-            })
+                    &self.sort_options,
+                );
+                DisplayShow {
+                    rating: rating.map(|rating| rating.to_string()).unwrap_or_default(),
+                    last_updated,
+                    position,
+                    details: ShowDetails {
+                        aliases,
+                        tags,
+                        notes,
+                    },
+                    ..show
+                }
+            },
+        )
     }
 }
@@ -1,11 +1,17 @@
-use std::{iter::Zip, ops::RangeFrom, slice::IterMut};
-
-use memchr::memmem;
-use strumbra::UniqueString;
+use std::{cmp::Ordering, collections::HashSet, ops::Range};
 
 use crate::{
-    show::{AdderShow, CategorizedShows, DisplayShow, ShowCategory},
-    shows_db::ShowsDb,
+    export,
+    fuzzy::{self, FuzzyMatch, FuzzyMatcher},
+    i18n::tr,
+    query::{Query, QueryError},
+    search_key::{original_positions, search_key},
+    show::{
+        AdderShow, CategorizedShows, DisplayShow, ShowCategory, ShowDetails, split_list, unix_now,
+    },
+    shows_db::{Activity, DEFAULT_PROFILE_ID, Profile, ShowsDb, SmartFilter},
+    sorting::SortOptions,
+    stats::Statistics,
 };
 
 #[derive(Copy, Clone, PartialEq)]
@@ -16,6 +22,35 @@ pub enum UiShowCategory {
     All = 3,
 }
 
+impl UiShowCategory {
+    pub const ALL: [UiShowCategory; 4] = [
+        UiShowCategory::Watching,
+        UiShowCategory::PlanToWatch,
+        UiShowCategory::Completed,
+        UiShowCategory::All,
+    ];
+
+    pub fn name(self) -> String {
+        match self {
+            UiShowCategory::Watching => ShowCategory::Watching.name(),
+            UiShowCategory::PlanToWatch => ShowCategory::PlanToWatch.name(),
+            UiShowCategory::Completed => ShowCategory::Completed.name(),
+            UiShowCategory::All => tr("category-all"),
+        }
+    }
+}
+
+impl TryFrom<i64> for UiShowCategory {
+    type Error = ();
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        UiShowCategory::ALL
+            .into_iter()
+            .find(|category| *category as i64 == value)
+            .ok_or(())
+    }
+}
+
 impl From<ShowCategory> for UiShowCategory {
     fn from(value: ShowCategory) -> Self {
         match value {
@@ -26,107 +61,896 @@ impl From<ShowCategory> for UiShowCategory {
     }
 }
 
-const SHOWS_PER_PAGE: usize = 10;
+const DEFAULT_SHOWS_PER_PAGE: usize = 10;
+pub const MAX_SHOWS_PER_PAGE: usize = 500;
+
+const SHOWS_PER_PAGE_SETTING: &str = "view.shows_per_page";
+const CONTINUOUS_SETTING: &str = "view.continuous";
+const PROFILE_SETTING: &str = "view.profile";
+
+// The All view's header above each category's shows. Collapsed categories
+// keep their header, and count, but leave their shows out of the ui shows.
+#[derive(Copy, Clone)]
+pub struct Groups {
+    // The ui index each category's header is drawn before, the number of
+    // ui shows when it comes after the last one.
+    pub starts: [usize; 3],
+    pub counts: [usize; 3],
+    pub collapsed: [bool; 3],
+}
+
+impl Groups {
+    // The headers drawn among the ui shows in `range`, as the ui index each
+    // comes before and its category. Headers at the end of the range are
+    // only drawn along with the last show.
+    pub fn headers(&self, range: Range<usize>, last: bool) -> Vec<(usize, usize)> {
+        (0..self.starts.len())
+            .map(|category| (self.starts[category], category))
+            .filter(|(start, _)| range.contains(start) || (last && *start == range.end))
+            .collect()
+    }
+
+    // The number of headers drawn before the show at `ui_index`.
+    pub fn headers_before(&self, ui_index: usize) -> usize {
+        self.starts
+            .iter()
+            .filter(|start| **start <= ui_index)
+            .count()
+    }
+
+    // Scrolling through every show, the headers take rows of their own.
+    // Map a range of those rows to the ui shows and headers drawn in it.
+    pub fn rows(&self, rows: Range<usize>) -> (Range<usize>, Vec<(usize, usize)>) {
+        let header_rows = |row: usize| {
+            self.starts
+                .iter()
+                .enumerate()
+                .filter(|(category, start)| *start + category < row)
+                .count()
+        };
+
+        let ui_range = rows.start - header_rows(rows.start)..rows.end - header_rows(rows.end);
+        let headers = (0..self.starts.len())
+            .filter(|category| rows.contains(&(self.starts[*category] + category)))
+            .map(|category| (self.starts[category], category))
+            .collect();
+        (ui_range, headers)
+    }
+}
+
+// Libraries with more shows than this are searched through the database.
+const FULL_TEXT_SEARCH_THRESHOLD: usize = 5000;
+
+const ALIAS_MATCH_PENALTY: i64 = 100;
+
+// The add form names only so many lookalikes of a new show.
+const SIMILAR_SHOWS: usize = 3;
+
+// The side panel only lists a show's latest changes.
+const ACTIVITY_LIMIT: i64 = 200;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum SortColumn {
+    Name,
+    Season,
+    Episodes,
+    Rating,
+    LastUpdated,
+}
+
+impl SortColumn {
+    const ALL: [SortColumn; 5] = [
+        SortColumn::Name,
+        SortColumn::Season,
+        SortColumn::Episodes,
+        SortColumn::Rating,
+        SortColumn::LastUpdated,
+    ];
+
+    fn setting_name(self) -> &'static str {
+        match self {
+            SortColumn::Name => "name",
+            SortColumn::Season => "season",
+            SortColumn::Episodes => "episodes",
+            SortColumn::Rating => "rating",
+            SortColumn::LastUpdated => "last_updated",
+        }
+    }
+
+    fn compare(self, left: &DisplayShow, right: &DisplayShow) -> Ordering {
+        let number = |field: &String| field.parse::<i64>().ok();
+        match self {
+            SortColumn::Name => Ordering::Equal,
+            SortColumn::Season => number(&left.season_number).cmp(&number(&right.season_number)),
+            SortColumn::Episodes => number(&left.episodes_seen).cmp(&number(&right.episodes_seen)),
+            SortColumn::Rating => left.rating().cmp(&right.rating()),
+            SortColumn::LastUpdated => left.last_updated.cmp(&right.last_updated),
+        }
+        .then_with(|| left.cmp(right))
+    }
+}
+
+// A column the user picked to order a category's shows by, instead of the
+// default of alphabetical, or best match first while searching.
+#[derive(Copy, Clone, PartialEq)]
+pub struct ColumnSort {
+    pub column: SortColumn,
+    pub descending: bool,
+}
+
+impl ColumnSort {
+    fn setting_key(category: UiShowCategory) -> String {
+        format!("view.sort.{}", category as usize)
+    }
+
+    fn to_setting(self) -> String {
+        let direction = if self.descending { "desc" } else { "asc" };
+        format!("{}:{}", self.column.setting_name(), direction)
+    }
+
+    fn from_setting(setting: &str) -> Option<ColumnSort> {
+        let (column, direction) = setting.split_once(':')?;
+        Some(ColumnSort {
+            column: *SortColumn::ALL
+                .iter()
+                .find(|sort_column| sort_column.setting_name() == column)?,
+            descending: direction == "desc",
+        })
+    }
+}
 
 pub struct ShowsView {
     shows_db: ShowsDb,
+    profiles: Vec<Profile>,
+    current_profile: i64,
+    watch_together: bool,
+    together_profiles: Vec<i64>,
     categorized_shows: CategorizedShows,
+    // Shows of the profile left out of the categorized shows, as their names
+    // only differ in case from another show's.
+    hidden_duplicates: Vec<String>,
     ui_shows: Vec<DisplayShow>,
+    ui_highlights: Vec<Vec<usize>>,
+    index_letters: Vec<char>,
     search_term: String,
+    query_error: Option<QueryError>,
     current_category: UiShowCategory,
+    smart_filters: Vec<SmartFilter>,
+    active_smart_filter: Option<i64>,
+    column_sorts: [Option<ColumnSort>; 4],
+    groups: Groups,
+    // Names of the ui shows picked for a bulk change, and the ui index a
+    // shift-click selects from.
+    selection: HashSet<String>,
+    selection_anchor: Option<usize>,
+    shows_per_page: usize,
+    // Scroll through every show rather than paging through them.
+    continuous: bool,
     page_number: usize,
     page_count: usize,
+    // Goes up whenever the ui shows are worked out again.
+    ui_revision: u64,
 }
 
 impl ShowsView {
-    fn calculate_num_pages(ui_shows_len: usize) -> usize {
+    fn calculate_num_pages(ui_shows_len: usize, shows_per_page: usize) -> usize {
         usize::max(
-            ((ui_shows_len as f64 / SHOWS_PER_PAGE as f64).ceil()) as usize,
+            ((ui_shows_len as f64 / shows_per_page as f64).ceil()) as usize,
             1,
         )
     }
 
-    pub fn new() -> Self {
-        let shows_db = ShowsDb::new();
-
+    // The shows of the profile, along with the names of those hidden as
+    // case duplicates.
+    fn load_categorized_shows(
+        shows_db: &ShowsDb,
+        profile_id: i64,
+    ) -> (CategorizedShows, Vec<String>) {
         let mut categorized_shows = CategorizedShows::default();
-        shows_db.load_all_shows().for_each(|show| {
+        shows_db.load_all_shows(profile_id).for_each(|show| {
             categorized_shows[show.category as usize].push(show);
         });
+        ShowsView::check_sorted(&mut categorized_shows);
+        let hidden_duplicates = ShowsView::hide_case_duplicates(&mut categorized_shows);
+        (categorized_shows, hidden_duplicates)
+    }
+
+    // Lookups binary search the categorized shows, so they must be in `Ord`
+    // order no matter what order the database handed them back in.
+    fn check_sorted(categorized_shows: &mut CategorizedShows) {
+        for shows in categorized_shows.iter_mut() {
+            if !shows.is_sorted() {
+                shows.sort();
+            }
+        }
+    }
+
+    // "Dark" and "dark" are different shows to the database but the same to
+    // `Ord`, so lookups could only ever find one of them. Any but the first
+    // are left alone in the database and reported instead.
+    fn hide_case_duplicates(categorized_shows: &mut CategorizedShows) -> Vec<String> {
+        let mut names = HashSet::new();
+        let mut hidden_duplicates = Vec::new();
+        for shows in categorized_shows.iter_mut() {
+            shows.retain(|show| {
+                let unique = names.insert(show.name().to_lowercase());
+                if !unique {
+                    hidden_duplicates.push(show.name().as_str().to_owned());
+                }
+                unique
+            });
+        }
+        hidden_duplicates
+    }
+
+    // The profile of the last session, unless it's gone.
+    pub fn last_profile(shows_db: &ShowsDb, profiles: &[Profile]) -> i64 {
+        shows_db
+            .setting(PROFILE_SETTING)
+            .and_then(|setting| setting.parse::<i64>().ok())
+            .filter(|profile_id| profiles.iter().any(|profile| profile.id == *profile_id))
+            .unwrap_or(DEFAULT_PROFILE_ID)
+    }
+
+    pub fn new() -> Self {
+        let shows_db = ShowsDb::new();
+        let profiles = shows_db.profiles();
+        let smart_filters = shows_db.smart_filters();
+
+        let current_profile = ShowsView::last_profile(&shows_db, &profiles);
+
+        let (categorized_shows, hidden_duplicates) =
+            ShowsView::load_categorized_shows(&shows_db, current_profile);
 
         // Present the user with the "Watching" category by default.
         let ui_shows = categorized_shows[ShowCategory::Watching as usize].to_owned();
         let ui_shows_len = ui_shows.len();
+        let ui_highlights = vec![Vec::new(); ui_shows_len];
+
+        let column_sorts = UiShowCategory::ALL.map(|category| {
+            shows_db
+                .setting(&ColumnSort::setting_key(category))
+                .and_then(|setting| ColumnSort::from_setting(&setting))
+        });
+
+        let shows_per_page = shows_db
+            .setting(SHOWS_PER_PAGE_SETTING)
+            .and_then(|setting| setting.parse().ok())
+            .unwrap_or(DEFAULT_SHOWS_PER_PAGE)
+            .clamp(1, MAX_SHOWS_PER_PAGE);
+        let continuous = shows_db
+            .setting(CONTINUOUS_SETTING)
+            .is_some_and(|setting| setting == "1");
 
-        ShowsView {
+        let mut shows_view = ShowsView {
             shows_db,
+            profiles,
+            current_profile,
+            watch_together: false,
+            together_profiles: Default::default(),
             categorized_shows,
+            hidden_duplicates,
             ui_shows,
+            ui_highlights,
+            index_letters: Vec::new(),
             search_term: Default::default(),
+            query_error: None,
             current_category: UiShowCategory::Watching,
+            smart_filters,
+            active_smart_filter: None,
+            column_sorts,
+            groups: Groups {
+                starts: [0; 3],
+                counts: [0; 3],
+                collapsed: [false; 3],
+            },
+            selection: HashSet::new(),
+            selection_anchor: None,
+            shows_per_page,
+            continuous,
             page_number: 1,
-            page_count: ShowsView::calculate_num_pages(ui_shows_len),
+            page_count: ShowsView::calculate_num_pages(ui_shows_len, shows_per_page),
+            ui_revision: 0,
+        };
+        shows_view.recalculate_ui_shows();
+        shows_view
+    }
+
+    pub fn profiles(&self) -> &[Profile] {
+        &self.profiles
+    }
+
+    pub fn current_profile(&self) -> i64 {
+        self.current_profile
+    }
+
+    pub fn switch_profile(&mut self, profile_id: i64) {
+        if profile_id == self.current_profile {
+            return;
+        }
+
+        self.current_profile = profile_id;
+        self.shows_db
+            .set_setting(PROFILE_SETTING, &profile_id.to_string());
+        self.together_profiles.retain(|id| *id != profile_id);
+        (self.categorized_shows, self.hidden_duplicates) =
+            ShowsView::load_categorized_shows(&self.shows_db, profile_id);
+
+        self.recalculate_ui_shows();
+    }
+
+    pub fn add_profile(&mut self, name: &str) {
+        let name = name.trim();
+        if name.is_empty() {
+            return;
         }
+
+        let profile_id = self.shows_db.add_profile(name);
+        self.profiles = self.shows_db.profiles();
+        self.switch_profile(profile_id);
     }
 
-    // Use the ui shows as buffers for user input, and rendering the ui
-    pub fn iter_mut(&mut self) -> Zip<RangeFrom<usize>, IterMut<'_, DisplayShow>> {
-        // ui_shows is empty
-        if self.ui_shows.is_empty() {
-            return (0usize..).zip(self.ui_shows.iter_mut());
+    pub fn sort_options(&self) -> &SortOptions {
+        self.shows_db.sort_options()
+    }
+
+    // Rebuild every sort key, the shows then have to be reloaded in the new order.
+    pub fn set_sort_options(&mut self, sort_options: SortOptions) {
+        if &sort_options == self.shows_db.sort_options() {
+            return;
         }
 
-        let begin_inclusive = (self.page_number - 1) * SHOWS_PER_PAGE;
-        let end_exclusive = usize::min(self.page_number * SHOWS_PER_PAGE, self.ui_shows.len());
+        self.shows_db.set_sort_options(sort_options);
+        (self.categorized_shows, self.hidden_duplicates) =
+            ShowsView::load_categorized_shows(&self.shows_db, self.current_profile);
 
-        let offset_index = subslice_index::subslice_index(
-            &self.ui_shows[..],
-            &self.ui_shows[begin_inclusive..end_exclusive],
-        );
+        self.recalculate_ui_shows();
+    }
 
-        (offset_index..).zip(self.ui_shows[begin_inclusive..end_exclusive].iter_mut())
+    pub fn watch_together(&mut self) -> &mut bool {
+        &mut self.watch_together
+    }
+
+    pub fn is_together_with(&self, profile_id: i64) -> bool {
+        self.together_profiles.contains(&profile_id)
+    }
+
+    pub fn set_together_with(&mut self, profile_id: i64, together: bool) {
+        self.together_profiles.retain(|id| *id != profile_id);
+        if together && profile_id != self.current_profile {
+            self.together_profiles.push(profile_id);
+        }
+    }
+
+    // The current profile, followed by everyone watching along with it.
+    fn progress_profiles(&self) -> Vec<i64> {
+        let mut profile_ids = vec![self.current_profile];
+        if self.watch_together {
+            profile_ids.extend_from_slice(&self.together_profiles);
+        }
+        profile_ids
+    }
+
+    // Use the ui shows as buffers for user input, and rendering the ui.
+    // Each show is paired with the characters of its name that matched the
+    // current search term.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, &mut DisplayShow, &[usize])> {
+        self.iter_mut_range(self.page_range())
+    }
+
+    // The ui indices on the current page, clamped to the shows there are.
+    pub fn page_range(&self) -> Range<usize> {
+        let end_exclusive = usize::min(self.page_number * self.shows_per_page, self.ui_shows.len());
+        let begin_inclusive =
+            usize::min((self.page_number - 1) * self.shows_per_page, end_exclusive);
+        begin_inclusive..end_exclusive
+    }
+
+    pub fn ui_show_mut(&mut self, ui_index: usize) -> Option<&mut DisplayShow> {
+        self.ui_shows.get_mut(ui_index)
+    }
+
+    // Where a show ended up among the ui shows, after an update may have
+    // moved it.
+    pub fn ui_index(&self, name: &str) -> Option<usize> {
+        self.ui_shows
+            .iter()
+            .position(|show| show.name().as_str() == name)
+    }
+
+    // Bring a show into the ui shows, dropping the search and switching to
+    // its category if they hide it, and return where it is.
+    pub fn reveal(&mut self, name: &str) -> Option<usize> {
+        if let Some(ui_index) = self.ui_index(name) {
+            return Some(ui_index);
+        }
+
+        let (category, _) = self.find_by_name(name.to_owned())?;
+        self.search_term.clear();
+        self.active_smart_filter = None;
+        self.current_category = UiShowCategory::try_from(category as i64).ok()?;
+        self.recalculate_ui_shows();
+        self.ui_index(name)
+    }
+
+    pub fn statistics(&self) -> Statistics {
+        Statistics::new(&self.shows_db, self.current_profile)
+    }
+
+    // Every show of the profile, whatever the category and search.
+    pub fn all_shows(&self) -> impl Iterator<Item = &DisplayShow> {
+        self.categorized_shows.iter().flatten()
+    }
+
+    // Like iter_mut, but over any range of the ui shows rather than the
+    // current page, clamped to the shows there are.
+    pub fn iter_mut_range(
+        &mut self,
+        range: Range<usize>,
+    ) -> impl Iterator<Item = (usize, &mut DisplayShow, &[usize])> {
+        let end_exclusive = usize::min(range.end, self.ui_shows.len());
+        let begin_inclusive = usize::min(range.start, end_exclusive);
+
+        let offset_index = if self.ui_shows.is_empty() {
+            // ui_shows is empty
+            0
+        } else {
+            subslice_index::subslice_index(
+                &self.ui_shows[..],
+                &self.ui_shows[begin_inclusive..end_exclusive],
+            )
+        };
+
+        (offset_index..)
+            .zip(
+                self.ui_shows[begin_inclusive..end_exclusive]
+                    .iter_mut()
+                    .zip(self.ui_highlights[begin_inclusive..end_exclusive].iter()),
+            )
+            .map(|(index, (show, highlights))| (index, show, &highlights[..]))
+    }
+
+    // The number of ui shows, over every page.
+    pub fn shows_count(&self) -> usize {
+        self.ui_shows.len()
+    }
+
+    fn category_shows(
+        &self,
+        category: UiShowCategory,
+    ) -> Box<dyn Iterator<Item = &DisplayShow> + '_> {
+        match category {
+            UiShowCategory::All => Box::new(self.categorized_shows.iter().flatten()),
+            _ => Box::new(self.categorized_shows[category as usize].iter()),
+        }
+    }
+
+    fn fuzzy_search(&self) -> (Vec<DisplayShow>, Vec<Vec<usize>>) {
+        let search_term = search_key(&self.search_term);
+        let matcher = FuzzyMatcher::new(&search_term);
+
+        let mut matches = self
+            .category_shows(self.current_category)
+            .filter_map(|show| {
+                let name_match = matcher.find(show.search_key().as_str());
+                // Matching an alias ranks a little below the same match on
+                // the name, and leaves nothing in the name to highlight.
+                let fuzzy_match = name_match.or_else(|| {
+                    split_list(&show.details.aliases)
+                        .filter_map(|alias| matcher.find(&search_key(alias)))
+                        .max_by_key(|alias_match| alias_match.score)
+                        .map(|alias_match| FuzzyMatch {
+                            score: alias_match.score - ALIAS_MATCH_PENALTY,
+                            positions: Vec::new(),
+                        })
+                });
+                fuzzy_match.map(|fuzzy_match| (show, fuzzy_match))
+            })
+            .collect::<Vec<_>>();
+
+        // Best matches first, ties stay in alphabetical order.
+        matches.sort_by_key(|(_, fuzzy_match)| std::cmp::Reverse(fuzzy_match.score));
+
+        matches
+            .into_iter()
+            .map(|(show, fuzzy_match)| {
+                let highlights = original_positions(show.name(), &fuzzy_match.positions);
+                (show.to_owned(), highlights)
+            })
+            .unzip()
+    }
+
+    // Large libraries leave the matching and ranking to the database's full
+    // text index, rather than scanning every show on every keystroke.
+    fn full_text_search(&self) -> (Vec<DisplayShow>, Vec<Vec<usize>>) {
+        let search_term = search_key(&self.search_term);
+        let matcher = FuzzyMatcher::new(&search_term);
+
+        self.shows_db
+            .search(&self.search_term)
+            .into_iter()
+            .filter_map(|name| self.find_by_name(name))
+            .map(|(category, index)| &self.categorized_shows[category][index])
+            .filter(|show| {
+                self.current_category == UiShowCategory::All
+                    || UiShowCategory::from(show.category) == self.current_category
+            })
+            .map(|show| {
+                let highlights = matcher
+                    .find(show.search_key().as_str())
+                    .map(|fuzzy_match| original_positions(show.name(), &fuzzy_match.positions))
+                    .unwrap_or_default();
+                (show.to_owned(), highlights)
+            })
+            .unzip()
+    }
+
+    fn name_search(&self) -> (Vec<DisplayShow>, Vec<Vec<usize>>) {
+        let library_len = self.categorized_shows.iter().map(Vec::len).sum::<usize>();
+        if library_len > FULL_TEXT_SEARCH_THRESHOLD {
+            self.full_text_search()
+        } else {
+            self.fuzzy_search()
+        }
     }
 
     fn recalculate_ui_shows(&mut self) {
-        self.ui_shows = match (self.current_category, self.search_term.is_empty()) {
-            (UiShowCategory::All, true) => {
-                self.categorized_shows.iter().flatten().cloned().collect()
-            }
+        self.ui_revision += 1;
+        if self.search_term.is_empty() {
+            self.query_error = None;
+        }
+
+        let (ui_shows, ui_highlights) = match (self.current_category, self.search_term.is_empty()) {
+            (UiShowCategory::All, true) => (
+                self.categorized_shows.iter().flatten().cloned().collect(),
+                Vec::new(),
+            ),
             (_, false) => {
-                let lower_search_term =
-                    UniqueString::try_from(self.search_term.to_lowercase()).unwrap();
-                let searcher = memmem::Finder::new(lower_search_term.as_bytes());
-
-                type DisplayShowIter<'a> = Box<dyn Iterator<Item = &'a DisplayShow> + 'a>;
-                match self.current_category {
-                    UiShowCategory::All => {
-                        Box::new(self.categorized_shows.iter().flatten()) as DisplayShowIter
+                self.query_error = None;
+                match Query::parse(&self.search_term) {
+                    Ok(query) if !query.is_plain() => {
+                        // Queries filtering on category look through every category.
+                        let category = match query.filters_category() {
+                            true => UiShowCategory::All,
+                            false => self.current_category,
+                        };
+                        let ui_shows = self
+                            .category_shows(category)
+                            .filter(|show| query.matches(show))
+                            .cloned()
+                            .collect();
+                        (ui_shows, Vec::new())
+                    }
+                    Ok(_) => self.name_search(),
+                    Err(error) => {
+                        // Fall back to searching for the whole thing as a name.
+                        self.query_error = Some(error);
+                        self.name_search()
                     }
-                    _ => Box::new(self.categorized_shows[self.current_category as usize].iter())
-                        as DisplayShowIter,
                 }
-                .filter_map(|show| {
-                    searcher
-                        .find(show.lower_name().as_bytes())
-                        .map(|_| show.to_owned())
-                })
-                .collect()
             }
-            (_, true) => self.categorized_shows[self.current_category as usize].to_owned(),
+            (_, true) => (
+                self.categorized_shows[self.current_category as usize].to_owned(),
+                Vec::new(),
+            ),
         };
+        self.ui_shows = ui_shows;
+        self.ui_highlights = ui_highlights;
+        self.ui_highlights.resize(self.ui_shows.len(), Vec::new());
+        // Not searching, so there are no highlights to keep in step.
+        if self.manual_order() {
+            self.ui_shows.sort_by_key(ShowsView::priority);
+        }
+        self.sort_ui_shows();
+        if self.grouped() {
+            self.group_ui_shows();
+        }
         self.page_number = 1;
-        self.page_count = ShowsView::calculate_num_pages(self.ui_shows.len());
+        self.page_count = ShowsView::calculate_num_pages(self.ui_shows.len(), self.shows_per_page);
+
+        let mut index_letters = self
+            .ui_shows
+            .iter()
+            .map(ShowsView::index_letter)
+            .collect::<Vec<_>>();
+        index_letters.sort_unstable();
+        index_letters.dedup();
+        self.index_letters = index_letters;
+
+        // Only shows in view stay selected, so a bulk change never reaches
+        // one the user can't see.
+        let ui_names = self
+            .ui_shows
+            .iter()
+            .map(|show| show.name().as_str())
+            .collect::<HashSet<_>>();
+        self.selection
+            .retain(|name| ui_names.contains(name.as_str()));
+        self.selection_anchor = None;
+    }
+
+    // Only the displayed order changes, the categorized shows stay in `Ord`
+    // order for lookups.
+    fn sort_ui_shows(&mut self) {
+        let Some(column_sort) = self.column_sort() else {
+            return;
+        };
+
+        let mut rows = std::mem::take(&mut self.ui_shows)
+            .into_iter()
+            .zip(std::mem::take(&mut self.ui_highlights))
+            .collect::<Vec<_>>();
+
+        rows.sort_by(|(left, _), (right, _)| {
+            let ordering = column_sort.column.compare(left, right);
+            if column_sort.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        (self.ui_shows, self.ui_highlights) = rows.into_iter().unzip();
+    }
+
+    // Keep the sorted order within each category, and drop the shows of
+    // collapsed ones.
+    fn group_ui_shows(&mut self) {
+        let mut rows = std::mem::take(&mut self.ui_shows)
+            .into_iter()
+            .zip(std::mem::take(&mut self.ui_highlights))
+            .collect::<Vec<_>>();
+        rows.sort_by_key(|(show, _)| show.category as usize);
+
+        let collapsed = self.groups.collapsed;
+        let mut counts = [0; 3];
+        for (show, _) in &rows {
+            counts[show.category as usize] += 1;
+        }
+        rows.retain(|(show, _)| !collapsed[show.category as usize]);
+
+        let mut start = 0;
+        for category in 0..counts.len() {
+            self.groups.starts[category] = start;
+            if !collapsed[category] {
+                start += counts[category];
+            }
+        }
+        self.groups.counts = counts;
+
+        (self.ui_shows, self.ui_highlights) = rows.into_iter().unzip();
+    }
+
+    // Plan to Watch shows come in the order the user put them in, rather
+    // than alphabetically, unless searching or sorting by a column.
+    pub fn manual_order(&self) -> bool {
+        matches!(
+            self.current_category,
+            UiShowCategory::PlanToWatch | UiShowCategory::All
+        ) && self.search_term.is_empty()
+            && self.column_sort().is_none()
+    }
+
+    // Other categories' shows keep their order, and the All view groups
+    // them by category afterwards.
+    fn priority(show: &DisplayShow) -> Option<i64> {
+        (show.category == ShowCategory::PlanToWatch).then(|| show.position.unwrap_or(i64::MAX))
+    }
+
+    // The Plan to Watch shows, highest priority first.
+    fn priority_order(&self) -> Vec<DisplayShow> {
+        let mut order = self.categorized_shows[ShowCategory::PlanToWatch as usize].to_owned();
+        order.sort_by_key(ShowsView::priority);
+        order
+    }
+
+    // Where a Plan to Watch show comes in its priority order, counting from 1.
+    pub fn priority_rank(&self, name: &str) -> Option<usize> {
+        self.priority_order()
+            .iter()
+            .position(|show| show.name().as_str() == name)
+            .map(|index| index + 1)
+    }
+
+    // Move a Plan to Watch show `step` places up or down its priority order.
+    pub fn step_priority(&mut self, name: &str, step: isize) {
+        let order = self.priority_order();
+        let Some(target) = order
+            .iter()
+            .position(|show| show.name().as_str() == name)
+            .and_then(|index| index.checked_add_signed(step))
+            .and_then(|index| order.get(index))
+        else {
+            return;
+        };
+        self.move_in_priority(name, target.name().as_str(), step > 0);
+    }
+
+    // Put the Plan to Watch show `name` just before or after `target`, and
+    // renumber the whole priority order. A show from another category
+    // joins Plan to Watch there.
+    pub fn move_in_priority(&mut self, name: &str, target: &str, after: bool) {
+        if name == target || !self.manual_order() {
+            return;
+        }
+        let Some((target_category, _)) = self.find_by_name(target.to_owned()) else {
+            return;
+        };
+        if target_category != ShowCategory::PlanToWatch as usize {
+            return;
+        }
+        let Some((category, index)) = self.find_by_name(name.to_owned()) else {
+            return;
+        };
+
+        let mut show = self.categorized_shows[category][index].to_owned();
+        if show.category != ShowCategory::PlanToWatch {
+            self.change_category(vec![show.to_owned()], ShowCategory::PlanToWatch);
+            show.category = ShowCategory::PlanToWatch;
+        }
+
+        let mut order = self.priority_order();
+        order.retain(|other| other.name() != show.name());
+        let Some(target_index) = order
+            .iter()
+            .position(|other| other.name().as_str() == target)
+        else {
+            return;
+        };
+        order.insert(target_index + usize::from(after), show);
+
+        // Only the shows that changed place are written.
+        let mut moved = Vec::new();
+        for (position, show) in order.iter_mut().enumerate() {
+            if show.position != Some(position as i64) {
+                show.position = Some(position as i64);
+                moved.push(show.to_owned());
+            }
+        }
+        self.shows_db.set_positions(self.current_profile, &moved);
+        for show in moved {
+            if let Some((category, index)) = self.locate(&show) {
+                self.categorized_shows[category][index].position = show.position;
+            }
+        }
+        self.refresh_ui_shows();
+    }
+
+    // The All view is shown grouped by category.
+    pub fn grouped(&self) -> bool {
+        self.current_category == UiShowCategory::All
+    }
+
+    pub fn groups(&self) -> Option<Groups> {
+        self.grouped().then_some(self.groups)
+    }
+
+    pub fn toggle_collapsed(&mut self, category: usize) {
+        self.groups.collapsed[category] = !self.groups.collapsed[category];
+        self.refresh_ui_shows();
+    }
+
+    // How many shows each category button leads to, regardless of the search.
+    pub fn category_count(&self, category: UiShowCategory) -> usize {
+        self.category_shows(category).count()
+    }
+
+    // The number of shows found by the search, counting those in collapsed
+    // groups.
+    pub fn match_count(&self) -> usize {
+        match self.groups() {
+            Some(groups) => groups.counts.iter().sum(),
+            None => self.ui_shows.len(),
+        }
+    }
+
+    pub fn column_sort(&self) -> Option<ColumnSort> {
+        self.column_sorts[self.current_category as usize]
+    }
+
+    // Clicking a column sorts by it ascending, then descending, then goes
+    // back to the default order.
+    pub fn sort_by(&mut self, column: SortColumn) {
+        let column_sort = match self.column_sort() {
+            Some(column_sort) if column_sort.column == column && !column_sort.descending => {
+                Some(ColumnSort {
+                    column,
+                    descending: true,
+                })
+            }
+            Some(column_sort) if column_sort.column == column => None,
+            _ => Some(ColumnSort {
+                column,
+                descending: false,
+            }),
+        };
+
+        self.column_sorts[self.current_category as usize] = column_sort;
+        self.shows_db.set_setting(
+            &ColumnSort::setting_key(self.current_category),
+            &column_sort.map(ColumnSort::to_setting).unwrap_or_default(),
+        );
+
+        self.recalculate_ui_shows();
+    }
+
+    // Bring the ui shows up to date with a change to the shows, staying on the
+    // same page where possible.
+    fn refresh_ui_shows(&mut self) {
+        let page_number = self.page_number;
+        self.recalculate_ui_shows();
+        self.page_number = usize::min(page_number, self.page_count);
     }
 
     pub fn search(&mut self) {
+        self.active_smart_filter = None;
         self.recalculate_ui_shows();
     }
 
+    pub fn smart_filters(&self) -> &[SmartFilter] {
+        &self.smart_filters
+    }
+
+    pub fn active_smart_filter(&self) -> Option<i64> {
+        self.active_smart_filter
+    }
+
+    pub fn apply_smart_filter(&mut self, id: i64) {
+        let Some(smart_filter) = self
+            .smart_filters
+            .iter()
+            .find(|smart_filter| smart_filter.id == id)
+        else {
+            return;
+        };
+
+        self.search_term = smart_filter.search_term.to_owned();
+        self.current_category = smart_filter
+            .category
+            .try_into()
+            .unwrap_or(UiShowCategory::All);
+        self.active_smart_filter = Some(id);
+
+        self.recalculate_ui_shows();
+    }
+
+    // Save the current search term and category under a name.
+    pub fn save_smart_filter(&mut self, name: &str) {
+        let name = name.trim();
+        if name.is_empty() {
+            return;
+        }
+
+        let id =
+            self.shows_db
+                .add_smart_filter(name, &self.search_term, self.current_category as i64);
+        self.smart_filters = self.shows_db.smart_filters();
+        self.active_smart_filter = Some(id);
+    }
+
+    pub fn remove_smart_filter(&mut self, id: i64) {
+        self.shows_db.remove_smart_filter(id);
+        self.smart_filters = self.shows_db.smart_filters();
+        if self.active_smart_filter == Some(id) {
+            self.active_smart_filter = None;
+        }
+    }
+
     pub fn search_box(&mut self) -> &mut String {
         &mut self.search_term
     }
 
+    // Why the search term could not be understood as a query, if it couldn't.
+    pub fn hidden_duplicates(&self) -> &[String] {
+        &self.hidden_duplicates
+    }
+
+    pub fn query_error(&self) -> Option<&QueryError> {
+        self.query_error.as_ref()
+    }
+
     pub fn update_category(&mut self) {
+        self.active_smart_filter = None;
         self.recalculate_ui_shows();
     }
 
@@ -150,6 +974,78 @@ impl ShowsView {
         self.page_number -= 1
     }
 
+    pub fn first_page(&mut self) {
+        self.page_number = 1;
+    }
+
+    pub fn last_page(&mut self) {
+        self.page_number = self.page_count;
+    }
+
+    pub fn set_page(&mut self, page_number: usize) {
+        self.page_number = page_number.clamp(1, self.page_count);
+    }
+
+    // Move to the page holding the ui show at ui_index.
+    pub fn show_page(&mut self, ui_index: usize) {
+        self.set_page(ui_index / self.shows_per_page + 1);
+    }
+
+    // The letters the ui shows start with, in alphabetical order.
+    pub fn index_letters(&self) -> &[char] {
+        &self.index_letters
+    }
+
+    // The ui index of the first show filed under letter.
+    pub fn first_with_letter(&self, letter: char) -> Option<usize> {
+        self.ui_shows
+            .iter()
+            .position(|show| ShowsView::index_letter(show) == letter)
+    }
+
+    // Shows are filed by the first letter of their sort key, so "The Wire"
+    // is under W when leading articles are ignored. Anything that doesn't
+    // start with a latin letter is filed under '#'.
+    fn index_letter(show: &DisplayShow) -> char {
+        show.sort_key()
+            .chars()
+            .next()
+            .and_then(|c| search_key(c.encode_utf8(&mut [0; 4])).chars().next())
+            .filter(char::is_ascii_alphabetic)
+            .map(|c| c.to_ascii_uppercase())
+            .unwrap_or('#')
+    }
+
+    pub fn shows_per_page(&self) -> usize {
+        self.shows_per_page
+    }
+
+    pub fn set_shows_per_page(&mut self, shows_per_page: usize) {
+        let shows_per_page = shows_per_page.clamp(1, MAX_SHOWS_PER_PAGE);
+        if shows_per_page == self.shows_per_page {
+            return;
+        }
+
+        // Stay around the first show of the current page.
+        let first_show = (self.page_number - 1) * self.shows_per_page;
+        self.shows_per_page = shows_per_page;
+        self.shows_db
+            .set_setting(SHOWS_PER_PAGE_SETTING, &shows_per_page.to_string());
+
+        self.page_count = ShowsView::calculate_num_pages(self.ui_shows.len(), shows_per_page);
+        self.page_number = usize::min(first_show / shows_per_page + 1, self.page_count);
+    }
+
+    pub fn continuous(&self) -> bool {
+        self.continuous
+    }
+
+    pub fn set_continuous(&mut self, continuous: bool) {
+        self.continuous = continuous;
+        self.shows_db
+            .set_setting(CONTINUOUS_SETTING, if continuous { "1" } else { "0" });
+    }
+
     pub fn page(&self) -> usize {
         self.page_number
     }
@@ -159,12 +1055,18 @@ impl ShowsView {
     }
 
     pub fn add(&mut self, show: AdderShow) {
-        let show = DisplayShow::new(
+        let display_show = DisplayShow::new(
             show.name,
             show.season_number,
             show.episodes_seen,
             show.category,
+            self.shows_db.sort_options(),
         );
+        let show = DisplayShow {
+            rating: show.rating,
+            last_updated: unix_now(),
+            ..display_show
+        };
 
         // Make sure someone isn't adding a show that already exists.
         let insert_index = match self.find_categorized_show(&show, Some(show.category as usize)) {
@@ -173,6 +1075,7 @@ impl ShowsView {
 
                 self.current_category = UiShowCategory::All;
                 self.search_term = existing_show.name().as_str().to_owned();
+                self.active_smart_filter = None;
 
                 self.recalculate_ui_shows();
                 return;
@@ -180,14 +1083,94 @@ impl ShowsView {
             Err(insert_index) => insert_index,
         };
 
-        self.shows_db.add(&show);
+        self.shows_db.add(&self.progress_profiles(), &show);
 
-        self.current_category = show.category.into();
-        self.categorized_shows[show.category as usize].insert(insert_index, show);
+        let category = show.category;
+        self.categorized_shows[category as usize].insert(insert_index, show);
+
+        if self.active_smart_filter.is_some() {
+            self.refresh_ui_shows();
+            return;
+        }
 
+        self.current_category = category.into();
         self.recalculate_ui_shows();
     }
 
+    // The category and index of a show, regardless of the category being
+    // viewed, since searches and smart filters can show any category.
+    fn locate(&self, show: &DisplayShow) -> Option<(usize, usize)> {
+        self.categorized_shows
+            .iter()
+            .enumerate()
+            .find_map(|(category, shows)| {
+                shows
+                    .binary_search(show)
+                    .ok()
+                    .map(|index| (category, index))
+            })
+    }
+
+    fn find_by_name(&self, name: String) -> Option<(usize, usize)> {
+        let show = DisplayShow::new(
+            name,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            self.shows_db.sort_options(),
+        );
+        self.locate(&show)
+    }
+
+    // A show of the profile by name, whatever the category and search.
+    pub fn show(&self, name: &str) -> Option<&DisplayShow> {
+        let (category, index) = self.find_by_name(name.to_owned())?;
+        Some(&self.categorized_shows[category][index])
+    }
+
+    // Shows that are likely `name` under another spelling, to warn about
+    // before it's added again.
+    pub fn similar_shows(&self, name: &str) -> Vec<String> {
+        let key = search_key(name);
+        self.all_shows()
+            .filter(|show| show.name().as_str() != name)
+            .filter(|show| fuzzy::similar(&key, show.search_key()))
+            .take(SIMILAR_SHOWS)
+            .map(|show| show.name().as_str().to_owned())
+            .collect()
+    }
+
+    // Changes along with the shows, in the database or in view, for what's
+    // looked up from them to be kept until then.
+    pub fn revision(&self) -> (u64, u64) {
+        (self.ui_revision, self.shows_db.total_changes())
+    }
+
+    // What happened to a show lately, in the current profile.
+    pub fn activity(&self, name: &str) -> Vec<Activity> {
+        self.shows_db
+            .activity(self.current_profile, name, ACTIVITY_LIMIT)
+    }
+
+    // Details are shared by every profile, and don't count as progress.
+    pub fn update_details(&mut self, name: &str, details: ShowDetails) {
+        let Some((category, index)) = self.find_by_name(name.to_owned()) else {
+            return;
+        };
+
+        let show = &mut self.categorized_shows[category][index];
+        show.details = details;
+        self.shows_db.update_details(std::slice::from_ref(show));
+
+        if let Some(ui_show) = self
+            .ui_shows
+            .iter_mut()
+            .find(|ui_show| ui_show.name() == show.name())
+        {
+            ui_show.details = show.details.to_owned();
+        }
+    }
+
     fn find_categorized_show<'a>(
         &'a self,
         show: &DisplayShow,
@@ -229,38 +1212,229 @@ impl ShowsView {
     }
 
     pub fn update(&mut self, ui_index: usize) {
-        let show = self.ui_shows[ui_index].to_owned();
+        self.ui_shows[ui_index].last_updated = unix_now();
+        let mut show = self.ui_shows[ui_index].to_owned();
 
-        let Ok((categorized_index, categorized_show)) = self.find_categorized_show(&show, None)
-        else {
+        let Some((category, categorized_index)) = self.locate(&show) else {
             return;
         };
+        // Leaving a category gives up the place in its priority order.
+        if category != show.category as usize {
+            show.position = None;
+        }
 
-        self.shows_db.update(&show);
+        self.shows_db
+            .update(&self.progress_profiles(), std::slice::from_ref(&show));
 
-        if categorized_show.category == show.category {
-            self.categorized_shows[categorized_show.category as usize][categorized_index] = show;
+        if category == show.category as usize {
+            self.categorized_shows[category][categorized_index] = show;
+        } else {
+            let Err(new_index) =
+                self.categorized_shows[show.category as usize].binary_search(&show)
+            else {
+                return;
+            };
+
+            self.categorized_shows[category].remove(categorized_index);
+            self.categorized_shows[show.category as usize].insert(new_index, show.to_owned());
+
+            // Follow the show to its new category, unless a smart filter is
+            // choosing what to show or every category is in view.
+            if self.active_smart_filter.is_none() && !self.grouped() {
+                self.current_category = show.category.into();
+                self.recalculate_ui_shows();
+                return;
+            }
+            self.refresh_ui_shows();
             return;
         }
 
-        let Err(new_index) = self.categorized_shows[show.category as usize].binary_search(&show)
-        else {
+        if self.active_smart_filter.is_some() {
+            self.refresh_ui_shows();
+        }
+    }
+
+    pub fn remove(&mut self, ui_index: usize) {
+        let show = self.ui_shows.remove(ui_index);
+        self.ui_highlights.remove(ui_index);
+
+        if let Some((category, categorized_index)) = self.locate(&show) {
+            let show = self.categorized_shows[category].remove(categorized_index);
+            self.shows_db.remove(std::slice::from_ref(&show));
+        }
+
+        if self.active_smart_filter.is_some() || self.grouped() {
+            self.refresh_ui_shows();
+        }
+    }
+
+    pub fn is_selected(&self, ui_index: usize) -> bool {
+        self.ui_shows
+            .get(ui_index)
+            .is_some_and(|show| self.selection.contains(show.name().as_str()))
+    }
+
+    pub fn selection_count(&self) -> usize {
+        self.selection.len()
+    }
+
+    // Select or deselect a show. Extending selects every show from the last
+    // one picked up to it.
+    pub fn select(&mut self, ui_index: usize, selected: bool, extend: bool) {
+        let range = match self.selection_anchor {
+            Some(anchor) if extend => anchor.min(ui_index)..anchor.max(ui_index) + 1,
+            _ => ui_index..ui_index + 1,
+        };
+        self.select_range(range, selected);
+        self.selection_anchor = Some(ui_index);
+    }
+
+    // Select or deselect the ui shows in range, such as those on the page.
+    pub fn select_range(&mut self, range: Range<usize>, selected: bool) {
+        let end = usize::min(range.end, self.ui_shows.len());
+        let start = usize::min(range.start, end);
+        for show in &self.ui_shows[start..end] {
+            let name = show.name().as_str();
+            if selected {
+                self.selection.insert(name.to_owned());
+            } else {
+                self.selection.remove(name);
+            }
+        }
+    }
+
+    // Every show the search and category let through, over all pages.
+    pub fn select_all(&mut self) {
+        self.select_range(0..self.ui_shows.len(), true);
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selection.clear();
+        self.selection_anchor = None;
+    }
+
+    // The selected shows as they are stored, in ui order.
+    fn selected_shows(&self) -> Vec<DisplayShow> {
+        self.ui_shows
+            .iter()
+            .filter(|show| self.selection.contains(show.name().as_str()))
+            .filter_map(|show| self.locate(show))
+            .map(|(category, index)| self.categorized_shows[category][index].to_owned())
+            .collect()
+    }
+
+    // Put the changed shows back in their categories, which may have changed.
+    fn replace_shows(&mut self, shows: Vec<DisplayShow>) {
+        for show in shows {
+            let Some((category, index)) = self.locate(&show) else {
+                continue;
+            };
+            self.categorized_shows[category].remove(index);
+
+            let shows = &mut self.categorized_shows[show.category as usize];
+            if let Err(index) = shows.binary_search(&show) {
+                shows.insert(index, show);
+            }
+        }
+        self.refresh_ui_shows();
+    }
+
+    // Unlike a single show, the view stays on the category the shows leave.
+    fn change_category(&mut self, shows: Vec<DisplayShow>, category: ShowCategory) {
+        let now = unix_now();
+        let shows = shows
+            .into_iter()
+            .filter(|show| show.category != category)
+            .map(|show| DisplayShow {
+                category,
+                last_updated: now,
+                position: None,
+                ..show
+            })
+            .collect::<Vec<_>>();
+
+        self.shows_db.update(&self.progress_profiles(), &shows);
+        self.replace_shows(shows);
+    }
+
+    pub fn set_selected_category(&mut self, category: ShowCategory) {
+        self.change_category(self.selected_shows(), category);
+    }
+
+    // Move a show dropped onto a category, along with the rest of the
+    // selection when it's part of one.
+    pub fn drop_on_category(&mut self, name: &str, category: ShowCategory) {
+        if self.selection.contains(name) {
+            self.set_selected_category(category);
+            return;
+        }
+
+        let Some((show_category, index)) = self.find_by_name(name.to_owned()) else {
             return;
         };
+        let show = self.categorized_shows[show_category][index].to_owned();
+        self.change_category(vec![show], category);
+    }
+
+    pub fn add_selected_tag(&mut self, tag: &str) {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return;
+        }
 
-        self.categorized_shows[categorized_show.category as usize].remove(categorized_index);
-        self.current_category = show.category.into();
-        self.categorized_shows[show.category as usize].insert(new_index, show);
+        let shows = self
+            .selected_shows()
+            .into_iter()
+            .filter(|show| !split_list(&show.details.tags).any(|existing| existing == tag))
+            .map(|mut show| {
+                show.details.tags = split_list(&show.details.tags)
+                    .chain(std::iter::once(tag))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                show
+            })
+            .collect::<Vec<_>>();
 
-        self.recalculate_ui_shows();
+        self.shows_db.update_details(&shows);
+        self.replace_shows(shows);
     }
 
-    pub fn remove(&mut self, ui_index: usize) {
-        let show = self.ui_shows.remove(ui_index);
+    pub fn remove_selected_tag(&mut self, tag: &str) {
+        let tag = tag.trim();
+        let shows = self
+            .selected_shows()
+            .into_iter()
+            .filter(|show| split_list(&show.details.tags).any(|existing| existing == tag))
+            .map(|mut show| {
+                show.details.tags = split_list(&show.details.tags)
+                    .filter(|existing| *existing != tag)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                show
+            })
+            .collect::<Vec<_>>();
 
-        if let Ok((categorized_index, show)) = self.find_categorized_show(&show, None) {
-            let show = self.categorized_shows[show.category as usize].remove(categorized_index);
-            self.shows_db.remove(&show);
+        self.shows_db.update_details(&shows);
+        self.replace_shows(shows);
+    }
+
+    pub fn remove_selected(&mut self) {
+        let shows = self.selected_shows();
+        self.shows_db.remove(&shows);
+
+        for show in &shows {
+            if let Some((category, index)) = self.locate(show) {
+                self.categorized_shows[category].remove(index);
+            }
         }
+        self.clear_selection();
+        self.refresh_ui_shows();
+    }
+
+    // Write the selected shows to a CSV file, returning how many there were.
+    pub fn export_selected(&self, path: &str) -> Result<usize, String> {
+        let shows = self.selected_shows();
+        export::write_csv(path, &shows)?;
+        Ok(shows.len())
     }
 }
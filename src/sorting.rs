@@ -0,0 +1,180 @@
+use std::{cmp::Ordering, iter::Peekable, str::Chars};
+
+const DEFAULT_ARTICLES: [&str; 3] = ["the", "a", "an"];
+
+// How show names are turned into the keys they are ordered by.
+#[derive(Clone, PartialEq)]
+pub struct SortOptions {
+    // File "The Wire" under W rather than T.
+    pub ignore_articles: bool,
+    // Lowercase leading words to skip, for every language in the library.
+    pub articles: Vec<String>,
+}
+
+impl Default for SortOptions {
+    fn default() -> Self {
+        SortOptions {
+            ignore_articles: false,
+            articles: DEFAULT_ARTICLES.map(str::to_owned).to_vec(),
+        }
+    }
+}
+
+impl SortOptions {
+    pub fn parse_articles(articles: &str) -> Vec<String> {
+        articles
+            .split(',')
+            .map(|article| article.trim().to_lowercase())
+            .filter(|article| !article.is_empty())
+            .collect()
+    }
+
+    pub fn articles_text(&self) -> String {
+        self.articles.join(", ")
+    }
+
+    pub fn sort_key(&self, name: &str) -> String {
+        let lower_name = name.to_lowercase();
+        if !self.ignore_articles {
+            return lower_name;
+        }
+
+        for article in &self.articles {
+            let Some(rest) = lower_name.strip_prefix(article.as_str()) else {
+                continue;
+            };
+            // Only whole words, and never the entire name ("The Office", not "Them").
+            // Elided articles such as "l'" run straight into the next word.
+            let elided = article.ends_with(['\'', '’']);
+            let title = rest.trim_start();
+            if (elided || rest.starts_with(char::is_whitespace)) && !title.is_empty() {
+                return title.to_owned();
+            }
+        }
+        lower_name
+    }
+
+    pub fn compare_names(&self, left: &str, right: &str) -> Ordering {
+        compare_sort_keys(&self.sort_key(left), left, &self.sort_key(right), right)
+    }
+}
+
+// Names with the same key ("The Office" and "Office") are told apart by the
+// rest of their name, so only names differing in case compare equal.
+pub fn compare_sort_keys(left_key: &str, left: &str, right_key: &str, right: &str) -> Ordering {
+    natural_cmp(left_key, right_key)
+        .then_with(|| natural_cmp(&left.to_lowercase(), &right.to_lowercase()))
+}
+
+fn take_number(chars: &mut Peekable<Chars>) -> String {
+    let mut digits = String::new();
+    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+        digits.push(digit);
+    }
+    digits
+}
+
+// Compare runs of digits by their value, so "Season 2" sorts before
+// "Season 10". Names equal by value ("Part 01", "Part 1") fall back to a
+// plain comparison to keep the order total.
+pub fn natural_cmp(left: &str, right: &str) -> Ordering {
+    let mut left_chars = left.chars().peekable();
+    let mut right_chars = right.chars().peekable();
+
+    loop {
+        match (left_chars.peek(), right_chars.peek()) {
+            (None, None) => return left.cmp(right),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+                let left_number = take_number(&mut left_chars);
+                let right_number = take_number(&mut right_chars);
+                let left_number = left_number.trim_start_matches('0');
+                let right_number = right_number.trim_start_matches('0');
+
+                let ordering = left_number
+                    .len()
+                    .cmp(&right_number.len())
+                    .then_with(|| left_number.cmp(right_number));
+                if ordering.is_ne() {
+                    return ordering;
+                }
+            }
+            (Some(l), Some(r)) => {
+                let ordering = l.cmp(r);
+                if ordering.is_ne() {
+                    return ordering;
+                }
+                left_chars.next();
+                right_chars.next();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ignoring(articles: &str) -> SortOptions {
+        SortOptions {
+            ignore_articles: true,
+            articles: SortOptions::parse_articles(articles),
+        }
+    }
+
+    #[test]
+    fn numbers_compare_by_value() {
+        assert_eq!(natural_cmp("part 2", "part 10"), Ordering::Less);
+        assert_eq!(natural_cmp("season 10", "season 9"), Ordering::Greater);
+        assert_eq!(natural_cmp("24", "3"), Ordering::Greater);
+        assert_eq!(natural_cmp("part 2 b", "part 2 a"), Ordering::Greater);
+    }
+
+    #[test]
+    fn leading_zeros_keep_the_order_total() {
+        assert_eq!(natural_cmp("part 007", "part 10"), Ordering::Less);
+        assert_eq!(natural_cmp("part 02", "part 2"), Ordering::Less);
+        assert_eq!(natural_cmp("part 2", "part 02"), Ordering::Greater);
+        assert_eq!(natural_cmp("part 02", "part 02"), Ordering::Equal);
+    }
+
+    #[test]
+    fn articles_are_skipped() {
+        let options = ignoring("the, a, an");
+        assert_eq!(options.sort_key("The Wire"), "wire");
+        assert_eq!(options.sort_key("A  Team"), "team");
+        assert_eq!(options.sort_key("Them"), "them");
+        assert_eq!(options.sort_key("The"), "the");
+        assert_eq!(
+            options.sort_key("Theory of Everything"),
+            "theory of everything"
+        );
+        assert_eq!(SortOptions::default().sort_key("The Wire"), "the wire");
+    }
+
+    #[test]
+    fn elided_articles_are_skipped() {
+        let options = ignoring("le, la, l', l’");
+        assert_eq!(options.sort_key("L'Arnacoeur"), "arnacoeur");
+        assert_eq!(options.sort_key("L’Arnacoeur"), "arnacoeur");
+        assert_eq!(options.sort_key("La Casa de Papel"), "casa de papel");
+        assert_eq!(options.sort_key("L'"), "l'");
+        assert_eq!(ignoring("the").sort_key("L'Arnacoeur"), "l'arnacoeur");
+    }
+
+    #[test]
+    fn only_case_ties() {
+        let options = ignoring("the");
+        assert_eq!(options.compare_names("Dark", "dark"), Ordering::Equal);
+        assert_eq!(
+            options.compare_names("The Office", "Office"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            options.compare_names("Office", "The Office"),
+            Ordering::Less
+        );
+        assert_eq!(options.compare_names("The Wire", "Veep"), Ordering::Greater);
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use crate::{
+    i18n::{self, decimal, tr, tr_args},
+    search_key::search_key,
+    show::{MAX_RATING, ShowCategory, split_list},
+    shows_db::ShowsDb,
+    shows_view::ShowsView,
+};
+
+// How far back the episode charts reach.
+const CHART_PERIODS: i64 = 12;
+const TOP_TAGS: usize = 10;
+
+// An overview of one profile's library.
+pub struct Statistics {
+    // Indexed by ShowCategory.
+    pub category_counts: [usize; 3],
+    pub episodes_seen: i64,
+    pub average_rating: Option<f64>,
+    // The share of shows that are completed.
+    pub completion_rate: Option<f64>,
+    pub top_tags: Vec<(String, usize)>,
+    pub episodes_per_week: Vec<(String, i64)>,
+    pub episodes_per_month: Vec<(String, i64)>,
+}
+
+impl Statistics {
+    pub fn new(shows_db: &ShowsDb, profile_id: i64) -> Self {
+        let mut category_counts = [0; 3];
+        let mut episodes_seen = 0;
+        let mut ratings = Vec::new();
+        // Tags differing only in case or accents count as one, under the
+        // first spelling seen.
+        let mut tags: HashMap<String, (String, usize)> = HashMap::new();
+
+        for show in shows_db.load_all_shows(profile_id) {
+            category_counts[show.category as usize] += 1;
+            episodes_seen += show.episodes_seen.parse::<i64>().unwrap_or_default();
+            ratings.extend(show.rating());
+            for tag in split_list(&show.details.tags) {
+                tags.entry(search_key(tag))
+                    .or_insert_with(|| (tag.to_owned(), 0))
+                    .1 += 1;
+            }
+        }
+
+        let shows_count = category_counts.iter().sum::<usize>();
+        let average_rating = (!ratings.is_empty())
+            .then(|| ratings.iter().sum::<i64>() as f64 / ratings.len() as f64);
+        let completion_rate = (shows_count > 0)
+            .then(|| category_counts[ShowCategory::Completed as usize] as f64 / shows_count as f64);
+
+        let mut top_tags = tags.into_values().collect::<Vec<_>>();
+        top_tags.sort_by(|(left_tag, left_count), (right_tag, right_count)| {
+            right_count
+                .cmp(left_count)
+                .then_with(|| left_tag.cmp(right_tag))
+        });
+        top_tags.truncate(TOP_TAGS);
+
+        Statistics {
+            category_counts,
+            episodes_seen,
+            average_rating,
+            completion_rate,
+            top_tags,
+            episodes_per_week: shows_db.episodes_per_week(profile_id, CHART_PERIODS),
+            episodes_per_month: shows_db.episodes_per_month(profile_id, CHART_PERIODS),
+        }
+    }
+
+    pub fn average_rating_text(&self) -> String {
+        match self.average_rating {
+            Some(average_rating) => tr_args(
+                "statistics-rating-value",
+                [
+                    ("rating", decimal(average_rating, 1)),
+                    ("max", MAX_RATING.into()),
+                ],
+            ),
+            None => "-".to_owned(),
+        }
+    }
+
+    pub fn completion_rate_text(&self) -> String {
+        match self.completion_rate {
+            Some(completion_rate) => tr_args(
+                "statistics-percent",
+                [("percent", decimal(completion_rate * 100f64, 0))],
+            ),
+            None => "-".to_owned(),
+        }
+    }
+
+    // The numbers as plain text, for the command line.
+    pub fn report(&self) -> String {
+        let line = |label: String, value: String| {
+            tr_args(
+                "statistics-report-line",
+                [("label", label.into()), ("value", value.into())],
+            ) + "\n"
+        };
+
+        let mut report = String::new();
+        for (category, count) in ShowCategory::ALL.iter().zip(self.category_counts) {
+            report.push_str(&line(category.name(), i18n::format_number(count as i64)));
+        }
+        report.push_str(&line(
+            tr("statistics-episodes-seen"),
+            i18n::format_number(self.episodes_seen),
+        ));
+        report.push_str(&line(
+            tr("statistics-average-rating"),
+            self.average_rating_text(),
+        ));
+        report.push_str(&line(
+            tr("statistics-completion-rate"),
+            self.completion_rate_text(),
+        ));
+
+        report.push_str(&format!("\n{}:\n", tr("statistics-top-tags")));
+        for (tag, count) in &self.top_tags {
+            report.push_str(&format!(
+                "  {}",
+                line(tag.to_owned(), i18n::format_number(*count as i64))
+            ));
+        }
+
+        for (title, periods, period_text) in [
+            (
+                tr("statistics-episodes-per-week"),
+                &self.episodes_per_week,
+                i18n::format_date as fn(&str) -> String,
+            ),
+            (
+                tr("statistics-episodes-per-month"),
+                &self.episodes_per_month,
+                i18n::format_month,
+            ),
+        ] {
+            report.push_str(&format!("\n{}:\n", title));
+            for (period, episodes) in periods {
+                report.push_str(&format!(
+                    "  {}",
+                    line(period_text(period), i18n::format_number(*episodes))
+                ));
+            }
+        }
+        report
+    }
+}
+
+// `vcc stats [profile]`, for the given profile or the one last used in the
+// window.
+pub fn print_statistics(profile_name: Option<String>) {
+    let shows_db = ShowsDb::new();
+    let profiles = shows_db.profiles();
+
+    let profile_id = match profile_name {
+        Some(profile_name) => {
+            let Some(profile) = profiles.iter().find(|profile| profile.name == profile_name) else {
+                eprintln!(
+                    "{}",
+                    tr_args(
+                        "statistics-unknown-profile",
+                        [("name", profile_name.into())]
+                    )
+                );
+                std::process::exit(1);
+            };
+            profile.id
+        }
+        None => ShowsView::last_profile(&shows_db, &profiles),
+    };
+
+    print!("{}", Statistics::new(&shows_db, profile_id).report());
+}
@@ -1,32 +1,524 @@
-use crate::show::{AdderShow, ShowCategory};
-use crate::shows_view::{ShowsView, UiShowCategory};
+use crate::fuzzy::FuzzyMatcher;
+use crate::i18n::{self, Language, tr, tr_args};
+use crate::keymap::{self, Action, Keymap, KeymapProblem, ShortcutError};
+use crate::search_key::{original_positions, search_key};
+use crate::settings::{Density, Font, Settings};
+use crate::show::{
+    AdderShow, DisplayShow, MAX_RATING, ShowCategory, ShowDetails, unix_now, valid_rating,
+};
+use crate::shows_db::Activity;
+use crate::shows_view::{
+    ColumnSort, Groups, MAX_SHOWS_PER_PAGE, ShowsView, SortColumn, UiShowCategory,
+};
+use crate::sorting::SortOptions;
+use crate::stats::Statistics;
 use eframe::egui;
+use std::ops::Range;
 
+pub const DEFAULT_WINDOW_SIZE: egui::Vec2 = egui::Vec2::new(1280f32, 800f32);
+
+// Where the view is kept between sessions, along with the window geometry and
+// egui's memory, which holds the theme. The page size and column sorts are
+// kept in the database, and so is the active profile.
+const CATEGORY_KEY: &str = "vcc.category";
+const SEARCH_KEY: &str = "vcc.search";
+const SMART_FILTER_KEY: &str = "vcc.smart_filter";
+
+// Text boxes start out this wide, and grow to fit what's in them.
 const NUMBER_LABEL_WIDTH: f32 = 40f32;
 const TEXT_LABEL_WIDTH: f32 = 125f32;
 
+// The details side panel, until it's resized.
+const DETAILS_PANEL_WIDTH: f32 = 360f32;
+const DETAILS_PANEL_MIN_WIDTH: f32 = 240f32;
+const NOTES_ROWS: usize = 10;
+
+// Text boxes the keyboard shortcuts move the focus to.
+const SEARCH_BOX_ID: &str = "search_box";
+const ADD_NAME_ID: &str = "add_show_name";
+
+// Windows and panels whose place and size are part of the layout.
+const CHEAT_SHEET_ID: &str = "cheat_sheet";
+const KEYMAP_EDITOR_ID: &str = "keymap_editor";
+const STATISTICS_ID: &str = "statistics";
+const SETTINGS_ID: &str = "settings";
+const DETAILS_PANEL_ID: &str = "show_details";
+
+// Lay out a show name with the characters matched by the search emphasized.
+fn highlighted_name(ui: &egui::Ui, name: &str, highlights: &[usize]) -> egui::text::LayoutJob {
+    let font_id = egui::TextStyle::Body.resolve(ui.style());
+    let normal = egui::TextFormat::simple(font_id.clone(), ui.visuals().text_color());
+    let highlighted = egui::TextFormat {
+        background: ui.visuals().selection.bg_fill,
+        ..egui::TextFormat::simple(font_id, ui.visuals().selection.stroke.color)
+    };
+
+    let mut job = egui::text::LayoutJob::default();
+    let mut highlights = highlights.iter().peekable();
+    for (index, (byte_index, c)) in name.char_indices().enumerate() {
+        let format = if highlights.next_if_eq(&&index).is_some() {
+            highlighted.clone()
+        } else {
+            normal.clone()
+        };
+        job.append(&name[byte_index..byte_index + c.len_utf8()], 0.0, format);
+    }
+    job
+}
+
+// Apply updater to a number typed into show_field, leaving anything that
+// isn't a number alone.
+fn change_number(show_field: &mut String, updater: impl FnOnce(i64) -> i64) -> bool {
+    let Ok(number) = show_field.parse::<i64>() else {
+        return false;
+    };
+    *show_field = format!("{}", updater(number));
+    true
+}
+
+// Shade the grid rows of the focused show and the selected ones, counting
+// the header as row 0.
+fn row_color(
+    focused_grid_row: Option<usize>,
+    selected_grid_rows: Vec<usize>,
+) -> impl Fn(usize, &egui::Style) -> Option<egui::Color32> {
+    move |grid_row, style| {
+        if Some(grid_row) == focused_grid_row {
+            Some(style.visuals.selection.bg_fill.gamma_multiply(0.4))
+        } else if selected_grid_rows.contains(&grid_row) {
+            Some(style.visuals.selection.bg_fill.gamma_multiply(0.2))
+        } else {
+            None
+        }
+    }
+}
+
+// The grid row of the show at ui index `row`, among the shows from
+// `first_row` on and the group headers drawn before it.
+fn grid_row(row: usize, first_row: usize, headers: &[(usize, usize)]) -> Option<usize> {
+    let headers_before = headers.iter().filter(|(start, _)| *start <= row).count();
+    row.checked_sub(first_row)
+        .map(|row| row + headers_before + 1)
+}
+
+// "Watching (12)"
+fn category_button_text(shows: &ShowsView, category: UiShowCategory) -> String {
+    tr_args(
+        "category-button",
+        [
+            ("category", category.name().into()),
+            ("count", shows.category_count(category).into()),
+        ],
+    )
+}
+
+fn last_updated_text(last_updated: i64) -> String {
+    const SECONDS_PER_DAY: i64 = 60 * 60 * 24;
+
+    if last_updated == 0 {
+        return tr("updated-never");
+    }
+
+    match (unix_now() - last_updated) / SECONDS_PER_DAY {
+        days if days <= 0 => tr("updated-today"),
+        1 => tr("updated-yesterday"),
+        days => tr_args("updated-days-ago", [("days", days.into())]),
+    }
+}
+
+// A change from a show's activity log, in words.
+fn activity_text(activity: &Activity) -> String {
+    // Unrated shows and new progress have no value to speak of.
+    let value = |value: &Option<String>| {
+        value
+            .as_deref()
+            .filter(|value| !value.is_empty())
+            .unwrap_or("none")
+            .to_owned()
+    };
+    let category = |value: &Option<String>| {
+        value
+            .as_deref()
+            .and_then(|value| value.parse::<i64>().ok())
+            .and_then(|value| ShowCategory::try_from(value).ok())
+            .map_or_else(|| "none".to_owned(), ShowCategory::name)
+    };
+    let (old, new) = match activity.field.as_str() {
+        "category" => (category(&activity.old_value), category(&activity.new_value)),
+        _ => (value(&activity.old_value), value(&activity.new_value)),
+    };
+
+    tr_args(
+        &format!("activity-{}", activity.field),
+        [
+            ("old", old.into()),
+            ("new", new.into()),
+            ("max", MAX_RATING.into()),
+        ],
+    )
+}
+
+// What a screen reader announces a control as, in place of its text. Only
+// worked out while one is listening.
+fn accessible_label(response: &egui::Response, label: impl FnOnce() -> String) {
+    response
+        .ctx
+        .accesskit_node_builder(response.id, |node| node.set_label(label()));
+}
+
+// Read out after the label, for what's otherwise only in a tooltip or seen
+// at a glance.
+fn accessible_description(response: &egui::Response, description: impl FnOnce() -> String) {
+    response
+        .ctx
+        .accesskit_node_builder(response.id, |node| node.set_description(description()));
+}
+
+// Everything a row shows about a show, for its name.
+fn row_description(show: &DisplayShow) -> String {
+    let rating = if show.rating.is_empty() {
+        tr("a11y-unrated")
+    } else {
+        tr_args(
+            "a11y-rated",
+            [
+                ("rating", show.rating.as_str().into()),
+                ("max", MAX_RATING.into()),
+            ],
+        )
+    };
+    tr_args(
+        "a11y-row",
+        [
+            ("category", show.category.name().into()),
+            ("season", show.season_number.as_str().into()),
+            ("episodes", show.episodes_seen.as_str().into()),
+            ("rating", rating.into()),
+            ("updated", last_updated_text(show.last_updated).into()),
+        ],
+    )
+}
+
+// The key bindings dialog, edited apart from the keymap in use until saved.
+struct KeymapEditor {
+    keymap: Keymap,
+    // The chords typed for each action, and why they don't parse.
+    texts: Vec<String>,
+    errors: Vec<Option<ShortcutError>>,
+    // Why the keymap file couldn't be written.
+    save_error: Option<String>,
+}
+
+impl KeymapEditor {
+    fn new(keymap: Keymap) -> Self {
+        let texts = Action::ALL
+            .map(|action| keymap::shortcuts_config_text(keymap.shortcuts(action)))
+            .to_vec();
+
+        KeymapEditor {
+            keymap,
+            texts,
+            errors: vec![None; Action::ALL.len()],
+            save_error: None,
+        }
+    }
+}
+
+// Something the command palette can do.
+#[derive(Clone)]
+enum PaletteCommand {
+    Action(Action),
+    Category(UiShowCategory),
+    SmartFilter(i64),
+    Profile(i64),
+    Show(String),
+}
+
+struct PaletteMatch {
+    command: PaletteCommand,
+    label: String,
+    // What kind of command it is, or its shortcuts.
+    detail: String,
+    highlights: Vec<usize>,
+}
+
+// The command palette while it's open.
+#[derive(Default)]
+struct Palette {
+    query: String,
+    selected: usize,
+    // Matched again only once the query changes, along with the query they
+    // were matched for.
+    matches: Vec<PaletteMatch>,
+    matched_query: Option<String>,
+}
+
+// What the delete confirmation is asking about.
+#[derive(Clone)]
+enum PendingDelete {
+    // A show by name, found again once confirmed in case the view changed.
+    Show(String),
+    // The selected shows, and how many there are.
+    Selection(usize),
+}
+
+// What's carried while a show is dragged by its handle.
+struct DraggedShow {
+    name: String,
+}
+
+// Why the add form can't be submitted as it is, field by field.
+struct AdderProblems {
+    name: Option<String>,
+    season_number: Option<String>,
+    episodes_seen: Option<String>,
+    rating: Option<String>,
+    // The show of that name that's already there.
+    duplicate: Option<String>,
+}
+
+impl AdderProblems {
+    fn new(adder: &AdderShow, shows: &ShowsView) -> Self {
+        let name = adder.name.trim();
+        let duplicate = shows.show(name).map(|show| show.name().as_str().to_owned());
+        let number = |number: &str| {
+            (!number.parse::<i64>().is_ok_and(|number| number >= 0)).then(|| tr("add-error-number"))
+        };
+
+        AdderProblems {
+            name: if name.is_empty() {
+                Some(tr("add-error-name"))
+            } else {
+                duplicate.as_ref().map(|_| tr("add-error-duplicate"))
+            },
+            season_number: number(&adder.season_number),
+            episodes_seen: number(&adder.episodes_seen),
+            rating: (!valid_rating(&adder.rating))
+                .then(|| tr_args("add-error-rating", [("max", MAX_RATING.into())])),
+            duplicate,
+        }
+    }
+
+    fn valid(&self) -> bool {
+        self.name.is_none()
+            && self.season_number.is_none()
+            && self.episodes_seen.is_none()
+            && self.rating.is_none()
+    }
+}
+
+// Only so many matches are worth scrolling through.
+const PALETTE_MATCHES: usize = 50;
+
 type AccumulatedModifications = Vec<Box<dyn FnOnce(&mut ShowsView)>>;
 
 pub struct Vcc {
     shows: ShowsView,
     adder: AdderShow,
+    // Whether the form was typed in since it was last cleared, and the shows
+    // its name looks like, along with the name and show count they were
+    // found for.
+    adder_edited: bool,
+    similar_shows: Vec<String>,
+    similar_to: (String, usize),
+    new_profile_name: String,
+    articles: String,
+    smart_filter_name: String,
+    // The show in the side panel, and its details as edited there. A row
+    // asks for the panel through details_request while the grid is drawn.
+    details_name: Option<String>,
+    details: ShowDetails,
+    details_request: Option<String>,
+    // What the panel looks up about its show, kept until the shows change.
+    details_revision: Option<(u64, u64)>,
+    details_ui_index: Option<usize>,
+    details_priority_rank: Option<usize>,
+    details_activity: Vec<Activity>,
+    // The continuous view's rows last on screen, and where to scroll it to.
+    visible_rows: Range<usize>,
+    scroll_to_row: Option<usize>,
+    // The show keyboard shortcuts act on, by ui index.
+    focused_row: Option<usize>,
+    pending_delete: Option<PendingDelete>,
+    // Single shows are deleted without asking once this is turned off, until
+    // vcc is closed.
+    confirm_delete: bool,
+    cheat_sheet: bool,
+    keymap: Keymap,
+    keymap_problems: Vec<KeymapProblem>,
+    keymap_editor: Option<KeymapEditor>,
+    palette: Option<Palette>,
+    // Worked out when the dashboard opens, rather than every frame.
+    statistics: Option<Statistics>,
+    settings: Settings,
+    settings_open: bool,
+    // Applied once the slider is let go, so that it doesn't rescale under
+    // the pointer.
+    ui_scale: f32,
+    font_path: String,
+    font_error: Option<String>,
+    // The tag and file the bulk actions work with.
+    selection_tag: String,
+    export_path: String,
+    export_result: Option<Result<String, String>>,
     accumulated_modifications: AccumulatedModifications,
 }
 
 impl Vcc {
-    pub fn new() -> Self {
-        Vcc {
-            shows: ShowsView::new(),
+    pub fn new(ctx: &egui::Context, storage: Option<&dyn eframe::Storage>) -> Self {
+        let shows = ShowsView::new();
+        let articles = shows.sort_options().articles_text();
+        let (keymap, keymap_problems) = Keymap::load();
+        // Point out a keymap file that didn't load cleanly.
+        let keymap_editor =
+            (!keymap_problems.is_empty()).then(|| KeymapEditor::new(keymap.clone()));
+
+        let settings = Settings::load(storage);
+        let font_error = settings.apply(ctx).err();
+        let font_path = match &settings.font {
+            Font::File(path) => path.to_owned(),
+            _ => String::new(),
+        };
+
+        let mut vcc = Vcc {
+            shows,
             adder: Default::default(),
+            adder_edited: false,
+            similar_shows: Vec::new(),
+            similar_to: Default::default(),
+            new_profile_name: Default::default(),
+            articles,
+            smart_filter_name: Default::default(),
+            details_name: None,
+            details: Default::default(),
+            details_request: None,
+            details_revision: None,
+            details_ui_index: None,
+            details_priority_rank: None,
+            details_activity: Vec::new(),
+            visible_rows: 0..0,
+            scroll_to_row: None,
+            focused_row: None,
+            pending_delete: None,
+            confirm_delete: true,
+            cheat_sheet: false,
+            keymap,
+            keymap_problems,
+            keymap_editor,
+            palette: None,
+            statistics: None,
+            settings,
+            settings_open: false,
+            ui_scale: ctx.zoom_factor(),
+            font_path,
+            font_error,
+            selection_tag: String::new(),
+            export_path: String::new(),
+            export_result: None,
             accumulated_modifications: Default::default(),
+        };
+        if let Some(storage) = storage {
+            vcc.restore(storage);
+        }
+        vcc
+    }
+
+    // Pick up the smart filter, or else the category and search, of the last
+    // session.
+    fn restore(&mut self, storage: &dyn eframe::Storage) {
+        if let Some(smart_filter) = storage
+            .get_string(SMART_FILTER_KEY)
+            .and_then(|smart_filter| smart_filter.parse::<i64>().ok())
+            .filter(|id| {
+                self.shows
+                    .smart_filters()
+                    .iter()
+                    .any(|smart_filter| smart_filter.id == *id)
+            })
+        {
+            self.shows.apply_smart_filter(smart_filter);
+            return;
+        }
+
+        if let Some(category) = storage
+            .get_string(CATEGORY_KEY)
+            .and_then(|category| category.parse::<i64>().ok())
+            .and_then(|category| UiShowCategory::try_from(category).ok())
+        {
+            *self.shows.current_category() = category;
+            self.shows.update_category();
+        }
+
+        if let Some(search_term) = storage.get_string(SEARCH_KEY) {
+            *self.shows.search_box() = search_term;
+            self.shows.search();
+        }
+    }
+
+    pub fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        storage.set_string(
+            CATEGORY_KEY,
+            (*self.shows.current_category() as i64).to_string(),
+        );
+        storage.set_string(SEARCH_KEY, self.shows.search_box().to_owned());
+        storage.set_string(
+            SMART_FILTER_KEY,
+            self.shows
+                .active_smart_filter()
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        );
+        self.settings.save(storage);
+    }
+
+    // Put the window and everything in it back where it first opened.
+    fn reset_layout(ctx: &egui::Context) {
+        // Only the layout, the rest of egui's memory holds the theme.
+        ctx.memory_mut(|memory| {
+            memory.reset_areas();
+            memory
+                .data
+                .remove::<egui::PanelState>(egui::Id::new(DETAILS_PANEL_ID));
+        });
+        for window in [CHEAT_SHEET_ID, KEYMAP_EDITOR_ID, STATISTICS_ID, SETTINGS_ID] {
+            if let Some(collapsing) = egui::collapsing_header::CollapsingState::load(
+                ctx,
+                egui::Id::new(window).with("collapsing"),
+            ) {
+                collapsing.remove(ctx);
+            }
+        }
+        ctx.send_viewport_cmd(egui::ViewportCommand::Maximized(false));
+        ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(DEFAULT_WINDOW_SIZE));
+        if let Some(center) = egui::ViewportCommand::center_on_screen(ctx) {
+            ctx.send_viewport_cmd(center);
         }
     }
 
     pub fn paint_ui(&mut self, ui: &mut egui::Ui) {
+        // Shortcuts get the first look at the keys, before any widget.
+        self.keyboard(ui.ctx());
+
+        // Keep the add form in view below however many rows are scrolled through.
+        egui::Panel::bottom("add_show").show_inside(ui, |ui| {
+            self.add(ui);
+        });
+        self.details_panel(ui);
+
         egui::CentralPanel::default().show_inside(ui, |ui| {
+            self.profiles(ui);
             self.search_page(ui);
+            self.selection_bar(ui);
             self.rows(ui);
-            self.add(ui);
+            if let Some(name) = self.details_request.take() {
+                self.open_details(name);
+            }
+            self.delete_confirmation(ui);
+            self.cheat_sheet(ui);
+            self.keymap_editor(ui);
+            self.command_palette(ui);
+            self.statistics_window(ui);
+            self.settings_window(ui);
 
             for modification in self.accumulated_modifications.drain(..) {
                 modification(&mut self.shows);
@@ -34,300 +526,2474 @@ impl Vcc {
         });
     }
 
+    fn profiles(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            let current_profile = self.shows.current_profile();
+            let current_profile_name = self
+                .shows
+                .profiles()
+                .iter()
+                .find(|profile| profile.id == current_profile)
+                .map(|profile| profile.name.to_owned())
+                .unwrap_or_default();
+
+            let profile_label = ui.label(tr("profile-label"));
+            let mut selected_profile = current_profile;
+            egui::ComboBox::from_id_salt(profile_label.id)
+                .selected_text(current_profile_name)
+                .show_ui(ui, |ui| {
+                    for profile in self.shows.profiles() {
+                        ui.selectable_value(&mut selected_profile, profile.id, &profile.name);
+                    }
+                });
+            if selected_profile != current_profile {
+                self.shows.switch_profile(selected_profile);
+            }
+
+            let new_profile_box = ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.new_profile_name)
+                        .hint_text(tr("profile-new-hint"))
+                        .desired_width(TEXT_LABEL_WIDTH)
+                        .clip_text(false),
+                )
+                .labelled_by(profile_label.id);
+            let submitted =
+                new_profile_box.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if ui.button(tr("profile-add")).clicked() || submitted {
+                self.shows.add_profile(&self.new_profile_name);
+                self.new_profile_name.clear();
+            }
+
+            ui.separator();
+
+            ui.checkbox(self.shows.watch_together(), tr("profile-watched-together"));
+            if *self.shows.watch_together() {
+                let other_profiles = self
+                    .shows
+                    .profiles()
+                    .iter()
+                    .filter(|profile| profile.id != self.shows.current_profile())
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                for profile in other_profiles {
+                    let mut together = self.shows.is_together_with(profile.id);
+                    if ui.checkbox(&mut together, &profile.name).changed() {
+                        self.shows.set_together_with(profile.id, together);
+                    }
+                }
+            }
+        });
+
+        ui.separator();
+    }
+
     fn search_page(&mut self, ui: &mut egui::Ui) {
         ui.horizontal(|ui| {
-            let search_box_label = ui.label("Search: ");
+            let search_box_label = ui.label(tr("search-label"));
             let search_box = ui
                 .add(
                     egui::TextEdit::singleline(self.shows.search_box())
-                        .desired_width(TEXT_LABEL_WIDTH),
+                        .id(egui::Id::new(SEARCH_BOX_ID))
+                        .desired_width(TEXT_LABEL_WIDTH)
+                        .clip_text(false),
                 )
-                .labelled_by(search_box_label.id);
+                .labelled_by(search_box_label.id)
+                .on_hover_text(tr("search-hint"));
+            accessible_description(&search_box, || tr("search-hint"));
             if search_box.changed() {
                 self.shows.search();
             }
 
+            if let Some(query_error) = self.shows.query_error() {
+                ui.colored_label(ui.visuals().error_fg_color, query_error.message())
+                    .on_hover_text(tr("search-query-error-hint"));
+            }
+
+            if !self.shows.hidden_duplicates().is_empty() {
+                ui.colored_label(
+                    ui.visuals().warn_fg_color,
+                    tr_args(
+                        "hidden-duplicates-warning",
+                        [("names", self.shows.hidden_duplicates().join(", ").into())],
+                    ),
+                )
+                .on_hover_text(tr("hidden-duplicates-hint"));
+            }
+
+            if !self.shows.search_box().is_empty() {
+                let matches_label = ui.weak(tr_args(
+                    "search-matches",
+                    [("count", self.shows.match_count().into())],
+                ));
+                // Read out as the search is typed.
+                matches_label
+                    .ctx
+                    .accesskit_node_builder(matches_label.id, |node| {
+                        node.set_live(egui::accesskit::Live::Polite);
+                    });
+            }
+
             ui.separator();
 
-            let category_label = ui.label("Category: ");
+            let category_label = ui.label(tr("category-label"));
+            let smart_filter_active = self.shows.active_smart_filter().is_some();
 
-            if ui
-                .add(egui::Button::selectable(
-                    *self.shows.current_category() == UiShowCategory::Watching,
-                    "Watching",
-                ))
-                .labelled_by(category_label.id)
-                .clicked()
-            {
-                *self.shows.current_category() = UiShowCategory::Watching;
-                self.shows.update_category();
+            for category in ShowCategory::ALL {
+                let ui_category = UiShowCategory::from(category);
+                let category_button = ui
+                    .add(egui::Button::selectable(
+                        !smart_filter_active && *self.shows.current_category() == ui_category,
+                        category_button_text(&self.shows, ui_category),
+                    ))
+                    .labelled_by(category_label.id);
+                if category_button.clicked() {
+                    *self.shows.current_category() = ui_category;
+                    self.shows.update_category();
+                }
+
+                // A show dragged by its handle moves to the category it's
+                // dropped on.
+                if category_button.dnd_hover_payload::<DraggedShow>().is_some() {
+                    ui.painter().rect_stroke(
+                        category_button.rect,
+                        2f32,
+                        ui.visuals().selection.stroke,
+                        egui::StrokeKind::Outside,
+                    );
+                }
+                if let Some(dragged) = category_button.dnd_release_payload::<DraggedShow>() {
+                    self.shows.drop_on_category(&dragged.name, category);
+                }
             }
 
             if ui
                 .add(egui::Button::selectable(
-                    *self.shows.current_category() == UiShowCategory::PlanToWatch,
-                    "Plan to Watch",
+                    !smart_filter_active && *self.shows.current_category() == UiShowCategory::All,
+                    category_button_text(&self.shows, UiShowCategory::All),
                 ))
                 .labelled_by(category_label.id)
                 .clicked()
             {
-                *self.shows.current_category() = UiShowCategory::PlanToWatch;
+                *self.shows.current_category() = UiShowCategory::All;
                 self.shows.update_category();
             }
 
-            if ui
-                .add(egui::Button::selectable(
-                    *self.shows.current_category() == UiShowCategory::Completed,
-                    "Completed",
-                ))
-                .labelled_by(category_label.id)
-                .clicked()
-            {
-                *self.shows.current_category() = UiShowCategory::Completed;
-                self.shows.update_category();
+            self.smart_filters(ui);
+
+            ui.separator();
+
+            if !self.shows.continuous() {
+                let page_label = ui.label(tr("page-label"));
+
+                let first_button = ui.button("⏮").on_hover_text(tr("page-first-hint"));
+                accessible_label(&first_button, || tr("action-first-page"));
+                if first_button.clicked() {
+                    self.shows.first_page();
+                }
+
+                let previous_button = ui.button("-").on_hover_text(tr("page-previous-hint"));
+                accessible_label(&previous_button, || tr("action-previous-page"));
+                if previous_button.clicked() {
+                    self.shows.previous_page();
+                }
+
+                let mut page = self.shows.page();
+                if ui
+                    .add(egui::DragValue::new(&mut page).range(1..=self.shows.page_count()))
+                    .labelled_by(page_label.id)
+                    .changed()
+                {
+                    self.shows.set_page(page);
+                }
+                ui.label(tr_args(
+                    "page-of",
+                    [("count", self.shows.page_count().into())],
+                ));
+
+                let next_button = ui.button("+").on_hover_text(tr("page-next-hint"));
+                accessible_label(&next_button, || tr("action-next-page"));
+                if next_button.clicked() {
+                    self.shows.next_page();
+                }
+
+                let last_button = ui.button("⏭").on_hover_text(tr("page-last-hint"));
+                accessible_label(&last_button, || tr("action-last-page"));
+                if last_button.clicked() {
+                    self.shows.last_page();
+                }
+
+                let page_size_label = ui.label(tr("page-size-label"));
+                let mut shows_per_page = self.shows.shows_per_page();
+                if ui
+                    .add(egui::DragValue::new(&mut shows_per_page).range(1..=MAX_SHOWS_PER_PAGE))
+                    .labelled_by(page_size_label.id)
+                    .changed()
+                {
+                    self.shows.set_shows_per_page(shows_per_page);
+                }
             }
 
+            let mut continuous = self.shows.continuous();
             if ui
-                .add(egui::Button::selectable(
-                    *self.shows.current_category() == UiShowCategory::All,
-                    "All",
-                ))
-                .labelled_by(category_label.id)
-                .clicked()
+                .checkbox(&mut continuous, tr("scroll"))
+                .on_hover_text(tr("scroll-hint"))
+                .changed()
             {
-                *self.shows.current_category() = UiShowCategory::All;
-                self.shows.update_category();
+                self.shows.set_continuous(continuous);
             }
 
             ui.separator();
 
-            ui.label("Page Number: ");
+            self.sort_menu(ui);
 
-            if ui.button("-").clicked() {
-                self.shows.previous_page();
+            let settings_button = ui
+                .button(format!("⚙ {}", tr("settings")))
+                .on_hover_text(self.keymap.shortcuts_text(ui.ctx(), Action::ShowSettings));
+            accessible_label(&settings_button, || tr("settings"));
+            if settings_button.clicked() {
+                self.open_settings(ui.ctx());
             }
 
-            ui.label(format!(
-                "{} of {}",
-                self.shows.page(),
-                self.shows.page_count(),
-            ));
+            let statistics_button = ui.button(format!("📊 {}", tr("statistics")));
+            accessible_label(&statistics_button, || tr("statistics"));
+            if statistics_button.clicked() {
+                self.statistics = Some(self.shows.statistics());
+            }
 
-            if ui.button("+").clicked() {
-                self.shows.next_page();
+            if ui
+                .button(tr("shortcuts"))
+                .on_hover_text(self.keymap.shortcuts_text(ui.ctx(), Action::CheatSheet))
+                .clicked()
+            {
+                self.cheat_sheet = !self.cheat_sheet;
             }
         });
 
+        self.letter_index(ui);
+
         ui.separator();
     }
 
-    fn rows(&mut self, ui: &mut egui::Ui) {
-        let changer = |show_field: &mut String, updater: &mut dyn FnMut(i64) -> i64| -> bool {
-            let Ok(number) = show_field.parse::<i64>() else {
-                return false;
-            };
-            *show_field = format!("{}", updater(number));
-            true
-        };
-        let modifications = &mut self.accumulated_modifications;
-
-        egui::Grid::new("display_show_grid").show(ui, |ui| {
-            for (index, show) in self.shows.iter_mut() {
-                ui.horizontal(|ui| {
-                    if ui.button("Del").clicked() {
-                        modifications.push(Box::new(move |shows: &mut ShowsView| {
-                            shows.remove(index);
-                        }));
+    // Jump to the first show filed under a letter.
+    fn letter_index(&mut self, ui: &mut egui::Ui) {
+        let mut jump_letter = None;
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing.x = 2f32;
+            for letter in std::iter::once('#').chain('A'..='Z') {
+                let has_shows = self.shows.index_letters().contains(&letter);
+                let letter_button =
+                    ui.add_enabled(has_shows, egui::Button::new(letter.to_string()).small());
+                accessible_label(&letter_button, || {
+                    if letter == '#' {
+                        tr("a11y-letter-other")
+                    } else {
+                        tr_args("a11y-letter", [("letter", letter.to_string().into())])
                     }
-                    ui.separator();
-                });
-
-                ui.horizontal(|ui| {
-                    ui.label("Name: ");
-                    ui.label(show.name().as_str());
                 });
+                if letter_button.clicked() {
+                    jump_letter = Some(letter);
+                }
+            }
+        });
 
-                ui.horizontal(|ui| {
-                    ui.separator();
+        if let Some(index) = jump_letter.and_then(|letter| self.shows.first_with_letter(letter)) {
+            self.go_to_show(index);
+            self.focused_row = Some(index);
+        }
+    }
 
-                    if ui.button("-").clicked()
-                        && changer(&mut show.season_number, &mut |curr| curr - 1)
-                    {
-                        modifications.push(Box::new(move |shows: &mut ShowsView| {
-                            shows.update(index);
-                        }));
-                    }
+    fn go_to_show(&mut self, ui_index: usize) {
+        if self.shows.continuous() {
+            self.scroll_to_row = Some(ui_index);
+        } else {
+            self.shows.show_page(ui_index);
+        }
+    }
 
-                    let season_label = ui.label("Season Number: ");
-                    let season_number_textbox = ui
-                        .add(
-                            egui::TextEdit::singleline(&mut show.season_number)
-                                .desired_width(NUMBER_LABEL_WIDTH),
-                        )
-                        .labelled_by(season_label.id);
+    // Bring a show into view without moving it to the top, unless it's on
+    // another page.
+    fn reveal_show(&mut self, ui_index: usize) {
+        if !self.shows.continuous() {
+            self.shows.show_page(ui_index);
+        } else if ui_index < self.visible_rows.start {
+            self.scroll_to_row = Some(ui_index);
+        } else if ui_index >= self.visible_rows.end {
+            self.scroll_to_row = Some((ui_index + 1).saturating_sub(self.visible_rows.len()));
+        }
+    }
 
-                    if season_number_textbox.changed()
-                        && changer(&mut show.season_number, &mut |curr| curr)
-                    {
-                        modifications.push(Box::new(move |shows: &mut ShowsView| {
-                            shows.update(index);
-                        }));
-                    }
+    fn keyboard(&mut self, ctx: &egui::Context) {
+        // The confirmation takes its own keys.
+        if self.pending_delete.is_some() {
+            return;
+        }
+        // So does the palette, save for the shortcut that closes it again.
+        if self.palette.is_some() {
+            if self.keymap.pressed(ctx, Action::CommandPalette) {
+                self.palette = None;
+            }
+            return;
+        }
 
-                    if ui.button("+").clicked()
-                        && changer(&mut show.season_number, &mut |curr| curr + 1)
-                    {
-                        modifications.push(Box::new(move |shows: &mut ShowsView| {
-                            shows.update(index);
-                        }));
-                    }
+        // Actions on a focused widget are left to where it's drawn.
+        for action in Action::ALL
+            .into_iter()
+            .filter(|action| !action.on_focused_widget())
+        {
+            if self.keymap.pressed(ctx, action) {
+                self.perform(ctx, action);
+            }
+        }
+    }
 
-                    ui.separator();
-                });
-                ui.horizontal(|ui| {
-                    if ui.button("-").clicked()
-                        && changer(&mut show.episodes_seen, &mut |curr| curr - 1)
-                    {
-                        modifications.push(Box::new(move |shows: &mut ShowsView| {
-                            shows.update(index);
-                        }));
-                    }
+    fn perform(&mut self, ctx: &egui::Context, action: Action) {
+        match action {
+            Action::FocusSearch => {
+                ctx.memory_mut(|m| m.request_focus(egui::Id::new(SEARCH_BOX_ID)));
+            }
+            Action::FocusAdd => {
+                ctx.memory_mut(|m| m.request_focus(egui::Id::new(ADD_NAME_ID)));
+            }
+            Action::CheatSheet => self.cheat_sheet = !self.cheat_sheet,
+            Action::CommandPalette => self.palette = Some(Default::default()),
+            Action::EditKeymap => {
+                self.keymap_editor = Some(KeymapEditor::new(self.keymap.clone()));
+            }
+            Action::ShowStatistics => self.statistics = Some(self.shows.statistics()),
+            Action::ShowSettings => self.open_settings(ctx),
+            Action::ResetLayout => Vcc::reset_layout(ctx),
+            Action::PreviousPage | Action::NextPage | Action::FirstPage | Action::LastPage => {
+                self.page_keys(action)
+            }
+            Action::PreviousRow => self.move_focus(-1),
+            Action::NextRow => self.move_focus(1),
+            _ => self.perform_on_focused(action),
+        }
+    }
 
-                    let episodes_seen_label = ui.label("Episodes Seen: ");
-                    let episodes_label_textbox = ui
-                        .add(
-                            egui::TextEdit::singleline(&mut show.episodes_seen)
-                                .desired_width(NUMBER_LABEL_WIDTH),
-                        )
-                        .labelled_by(episodes_seen_label.id);
+    fn perform_on_focused(&mut self, action: Action) {
+        let Some(row) = self
+            .focused_row
+            .filter(|row| *row < self.shows.shows_count())
+        else {
+            return;
+        };
 
-                    if episodes_label_textbox.changed()
-                        && changer(&mut show.episodes_seen, &mut |curr| curr)
-                    {
-                        modifications.push(Box::new(move |shows: &mut ShowsView| {
-                            shows.update(index);
-                        }));
-                    }
+        let category = match action {
+            Action::MarkWatching => Some(ShowCategory::Watching),
+            Action::MarkPlanToWatch => Some(ShowCategory::PlanToWatch),
+            Action::MarkCompleted => Some(ShowCategory::Completed),
+            _ => None,
+        };
+        if let Some(category) = category {
+            self.change_focused(row, |show| {
+                let changed = show.category != category;
+                show.category = category;
+                changed
+            });
+            return;
+        }
 
-                    if ui.button("+").clicked()
-                        && changer(&mut show.episodes_seen, &mut |curr| curr + 1)
-                    {
-                        modifications.push(Box::new(move |shows: &mut ShowsView| {
-                            shows.update(index);
-                        }));
-                    }
+        match action {
+            Action::FewerEpisodes => self.change_focused(row, |show| {
+                change_number(&mut show.episodes_seen, |curr| curr - 1)
+            }),
+            Action::MoreEpisodes => self.change_focused(row, |show| {
+                change_number(&mut show.episodes_seen, |curr| curr + 1)
+            }),
+            Action::PreviousSeason => self.change_focused(row, |show| {
+                change_number(&mut show.season_number, |curr| curr - 1)
+            }),
+            Action::NextSeason => self.change_focused(row, |show| {
+                change_number(&mut show.season_number, |curr| curr + 1)
+            }),
+            Action::ShowDetails => {
+                if let Some(show) = self.shows.ui_show_mut(row) {
+                    let name = show.name().as_str().to_owned();
+                    self.open_details(name);
+                }
+            }
+            Action::RaisePriority | Action::LowerPriority => {
+                let Some(show) = self.shows.ui_show_mut(row) else {
+                    return;
+                };
+                let name = show.name().as_str().to_owned();
+                let step = if action == Action::RaisePriority {
+                    -1
+                } else {
+                    1
+                };
+                self.shows.step_priority(&name, step);
+                self.focused_row = self.shows.ui_index(&name);
+                if let Some(row) = self.focused_row {
+                    self.reveal_show(row);
+                }
+            }
+            Action::DeleteShow => {
+                if let Some(show) = self.shows.ui_show_mut(row) {
+                    self.pending_delete =
+                        Some(PendingDelete::Show(show.name().as_str().to_owned()));
+                }
+            }
+            _ => (),
+        }
+    }
 
-                    ui.separator();
-                });
-                let category_label = ui.label("Category: ");
-                egui::ComboBox::from_id_salt(category_label.id)
-                    .selected_text(format!("{:?}", show.category))
-                    .show_ui(ui, |ui| {
-                        let watch = ui
-                            .selectable_value(
-                                &mut show.category,
-                                ShowCategory::Watching,
-                                "Watching",
-                            )
-                            .changed();
-                        let plan = ui
-                            .selectable_value(
-                                &mut show.category,
-                                ShowCategory::PlanToWatch,
-                                "PlanToWatch",
-                            )
-                            .changed();
-                        let complete = ui
-                            .selectable_value(
-                                &mut show.category,
-                                ShowCategory::Completed,
-                                "Completed",
-                            )
-                            .changed();
+    // Page Up, Page Down, Home and End page through the shows, or scroll
+    // through them a screenful at a time.
+    fn page_keys(&mut self, action: Action) {
+        if self.shows.continuous() {
+            // Keep a row of overlap between screenfuls.
+            let screen_rows = self.visible_rows.len().saturating_sub(1).max(1);
+            let last_row = self.shows.shows_count().saturating_sub(1);
+            self.scroll_to_row = match action {
+                Action::PreviousPage => Some(self.visible_rows.start.saturating_sub(screen_rows)),
+                Action::NextPage => {
+                    Some(usize::min(self.visible_rows.start + screen_rows, last_row))
+                }
+                Action::FirstPage => Some(0),
+                Action::LastPage => Some(last_row),
+                _ => self.scroll_to_row,
+            };
+            return;
+        }
 
-                        if watch || plan || complete {
-                            modifications.push(Box::new(move |shows: &mut ShowsView| {
-                                shows.update(index);
-                            }));
-                        }
-                    });
-                ui.end_row();
-            }
-        });
-        ui.separator();
+        match action {
+            Action::PreviousPage => self.shows.previous_page(),
+            Action::NextPage => self.shows.next_page(),
+            Action::FirstPage => self.shows.first_page(),
+            Action::LastPage => self.shows.last_page(),
+            _ => (),
+        }
     }
 
-    fn add(&mut self, ui: &mut egui::Ui) {
-        ui.label("Add new show");
-        ui.horizontal(|ui| {
-            let name_label = ui.label("Name: ");
-            ui.add(
-                egui::TextEdit::singleline(&mut self.adder.name).desired_width(TEXT_LABEL_WIDTH),
-            )
-            .labelled_by(name_label.id);
+    // Step the focus through the shows. A focus scrolled or paged out of
+    // view starts over from the first show in view.
+    fn move_focus(&mut self, step: isize) {
+        let shows_count = self.shows.shows_count();
+        if shows_count == 0 {
+            self.focused_row = None;
+            return;
+        }
 
-            ui.separator();
+        let in_view = if self.shows.continuous() {
+            self.visible_rows.to_owned()
+        } else {
+            self.shows.page_range()
+        };
+        let row = match self.focused_row {
+            Some(row) if in_view.contains(&row) => row.saturating_add_signed(step),
+            _ => in_view.start,
+        };
+        let row = usize::min(row, shows_count - 1);
 
-            let seasons_label = ui.label("Season Number: ");
-            ui.add(
-                egui::TextEdit::singleline(&mut self.adder.season_number)
-                    .desired_width(NUMBER_LABEL_WIDTH),
-            )
-            .labelled_by(seasons_label.id);
+        self.focused_row = Some(row);
+        self.reveal_show(row);
 
-            ui.separator();
+        // The side panel follows the focus.
+        let name = self
+            .shows
+            .ui_show_mut(row)
+            .map(|show| show.name().as_str().to_owned());
+        if let Some(name) = name {
+            self.open_details(name);
+        }
+    }
 
-            let episodes_label = ui.label("Episodes Seen: ");
-            ui.add(
-                egui::TextEdit::singleline(&mut self.adder.episodes_seen)
-                    .desired_width(NUMBER_LABEL_WIDTH),
-            )
-            .labelled_by(episodes_label.id);
+    // Change the focused show and keep the focus on it, wherever the update
+    // moves it to.
+    fn change_focused(&mut self, row: usize, change: impl FnOnce(&mut DisplayShow) -> bool) {
+        let Some(show) = self.shows.ui_show_mut(row) else {
+            return;
+        };
+        if !change(show) {
+            return;
+        }
 
-            ui.separator();
+        let name = show.name().as_str().to_owned();
+        self.shows.update(row);
+        self.focused_row = self.shows.ui_index(&name);
+        if let Some(row) = self.focused_row {
+            self.reveal_show(row);
+        }
+    }
 
-            let category_label = ui.label("Category: ");
-            egui::ComboBox::from_id_salt(category_label.id)
-                .selected_text(format!("{:?}", self.adder.category))
-                .show_ui(ui, |ui| {
-                    ui.selectable_value(
-                        &mut self.adder.category,
-                        ShowCategory::Watching,
-                        "Watching",
-                    );
-                    ui.selectable_value(
-                        &mut self.adder.category,
-                        ShowCategory::PlanToWatch,
-                        "PlanToWatch",
-                    );
+    fn delete_confirmation(&mut self, ui: &mut egui::Ui) {
+        let Some(pending_delete) = self.pending_delete.to_owned() else {
+            return;
+        };
+        if !self.confirm_delete && matches!(pending_delete, PendingDelete::Show(_)) {
+            self.delete(pending_delete);
+            return;
+        }
 
-                    ui.selectable_value(
-                        &mut self.adder.category,
-                        ShowCategory::Completed,
-                        "Completed",
-                    );
-                });
+        let (question, count) = match &pending_delete {
+            PendingDelete::Show(name) => (
+                tr_args("delete-question", [("name", name.as_str().into())]),
+                1,
+            ),
+            PendingDelete::Selection(count) => (
+                tr_args("delete-selection-question", [("count", (*count).into())]),
+                *count,
+            ),
+        };
+
+        let mut confirmed = false;
+        let mut cancelled = false;
+        let mut dont_ask = !self.confirm_delete;
+        let modal = egui::Modal::new(egui::Id::new("delete_confirmation")).show(ui.ctx(), |ui| {
+            ui.label(question);
+            ui.label(tr_args("delete-explanation", [("count", count.into())]));
+            let mut own_ids = Vec::new();
+            if matches!(pending_delete, PendingDelete::Show(_)) {
+                own_ids.push(ui.checkbox(&mut dont_ask, tr("delete-dont-ask")).id);
+            }
+            ui.horizontal(|ui| {
+                // Enter and Space only activate the focused button, which is
+                // Cancel until Tab moves on, so a stray Enter deletes nothing.
+                let delete_button = ui.add(Vcc::delete_button(ui, tr("delete")));
+                let cancel_button = ui.button(tr("cancel"));
+                own_ids.extend([delete_button.id, cancel_button.id]);
+                if !ui
+                    .memory(|m| m.focused())
+                    .is_some_and(|focused| own_ids.contains(&focused))
+                {
+                    cancel_button.request_focus();
+                }
+                confirmed = delete_button.clicked();
+                cancelled = cancel_button.clicked();
+            });
         });
-        if ui.button("Add").clicked() {
-            if self.adder.name.is_empty() {
-                self.adder.clear();
-                return;
-            }
-
-            let (Ok(_), Ok(_)) = (
-                self.adder.season_number.parse::<i64>(),
-                self.adder.episodes_seen.parse::<i64>(),
-            ) else {
-                self.adder.clear();
-                return;
-            };
 
-            let owned_adder = self.adder.to_owned();
-            self.accumulated_modifications
-                .push(Box::new(move |shows: &mut ShowsView| {
-                    shows.add(owned_adder);
-                }));
-            self.adder.clear();
+        if confirmed {
+            self.confirm_delete = !dont_ask;
+            self.delete(pending_delete);
+        } else if cancelled || modal.should_close() {
+            self.pending_delete = None;
         }
     }
+
+    fn delete(&mut self, pending_delete: PendingDelete) {
+        match pending_delete {
+            PendingDelete::Show(name) => {
+                if let Some(row) = self.shows.ui_index(&name) {
+                    self.shows.remove(row);
+                }
+            }
+            PendingDelete::Selection(_) => self.shows.remove_selected(),
+        }
+        self.pending_delete = None;
+    }
+
+    // Deleting is drawn in the error color, so it can't pass for one of the
+    // buttons around it.
+    fn delete_button(ui: &egui::Ui, text: String) -> egui::Button<'static> {
+        let visuals = ui.visuals();
+        egui::Button::new(egui::RichText::new(text).color(visuals.error_fg_color))
+            .fill(visuals.error_fg_color.gamma_multiply(0.15))
+            .stroke(egui::Stroke::new(1f32, visuals.error_fg_color))
+    }
+
+    fn cheat_sheet(&mut self, ui: &mut egui::Ui) {
+        if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.cheat_sheet = false;
+        }
+
+        let mut customize = false;
+        egui::Window::new(tr("cheat-sheet-title"))
+            .id(egui::Id::new(CHEAT_SHEET_ID))
+            .open(&mut self.cheat_sheet)
+            .collapsible(false)
+            .show(ui.ctx(), |ui| {
+                egui::Grid::new("cheat_sheet_grid")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for action in Action::ALL {
+                            ui.label(self.keymap.shortcuts_text(ui.ctx(), action));
+                            ui.label(action.description());
+                            ui.end_row();
+                        }
+                    });
+
+                customize = ui.button(tr("cheat-sheet-customize")).clicked();
+            });
+
+        if customize {
+            self.cheat_sheet = false;
+            self.keymap_editor = Some(KeymapEditor::new(self.keymap.clone()));
+        }
+    }
+
+    fn keymap_editor(&mut self, ui: &mut egui::Ui) {
+        let Some(editor) = &mut self.keymap_editor else {
+            return;
+        };
+
+        let mut open = true;
+        let mut saved = false;
+        egui::Window::new(tr("keymap-title"))
+            .id(egui::Id::new(KEYMAP_EDITOR_ID))
+            .open(&mut open)
+            .show(ui.ctx(), |ui| {
+                for problem in &self.keymap_problems {
+                    ui.colored_label(ui.visuals().error_fg_color, problem.message());
+                }
+                if let Some(error) = &editor.save_error {
+                    ui.colored_label(ui.visuals().error_fg_color, error);
+                }
+                for conflict in editor.keymap.conflicts(ui.ctx()) {
+                    ui.colored_label(ui.visuals().warn_fg_color, conflict);
+                }
+
+                egui::Grid::new("keymap_grid").striped(true).show(ui, |ui| {
+                    for action in Action::ALL {
+                        let index = action as usize;
+
+                        let action_label = ui.label(action.description());
+                        let chords_box = ui
+                            .add(
+                                egui::TextEdit::singleline(&mut editor.texts[index])
+                                    .hint_text(tr("keymap-unbound"))
+                                    .desired_width(TEXT_LABEL_WIDTH)
+                                    .clip_text(false),
+                            )
+                            .labelled_by(action_label.id)
+                            .on_hover_text(tr("keymap-keys-hint"));
+                        if chords_box.changed() {
+                            match keymap::parse_shortcuts(&editor.texts[index]) {
+                                Ok(shortcuts) => {
+                                    editor.keymap.set_shortcuts(action, shortcuts);
+                                    editor.errors[index] = None;
+                                }
+                                Err(error) => editor.errors[index] = Some(error),
+                            }
+                        }
+
+                        if ui.small_button(tr("keymap-default")).clicked() {
+                            editor
+                                .keymap
+                                .set_shortcuts(action, action.default_shortcuts());
+                            editor.texts[index] =
+                                keymap::shortcuts_config_text(editor.keymap.shortcuts(action));
+                            editor.errors[index] = None;
+                        }
+
+                        match &editor.errors[index] {
+                            Some(error) => {
+                                ui.colored_label(ui.visuals().error_fg_color, error.message())
+                            }
+                            None => ui.label(""),
+                        };
+                        ui.end_row();
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    saved = ui
+                        .add_enabled(
+                            editor.errors.iter().all(Option::is_none),
+                            egui::Button::new(tr("save")),
+                        )
+                        .clicked();
+                    if ui.button(tr("keymap-reset-all")).clicked() {
+                        *editor = KeymapEditor::new(Keymap::default());
+                    }
+                });
+            });
+
+        if saved {
+            // The keys apply to this session even when they can't be saved.
+            self.keymap = editor.keymap.clone();
+            self.keymap_problems.clear();
+            match self.keymap.save() {
+                Ok(()) => self.keymap_editor = None,
+                Err(error) => {
+                    editor.save_error =
+                        Some(tr_args("keymap-save-error", [("error", error.into())]));
+                }
+            }
+        }
+        if !open {
+            self.keymap_editor = None;
+        }
+    }
+
+    fn palette_matches(&self, ctx: &egui::Context, query: &str) -> Vec<PaletteMatch> {
+        let mut commands = Action::ALL
+            .into_iter()
+            .filter(|action| *action != Action::CommandPalette && !action.on_focused_widget())
+            .map(|action| {
+                (
+                    PaletteCommand::Action(action),
+                    action.description(),
+                    self.keymap.shortcuts_text(ctx, action),
+                )
+            })
+            .collect::<Vec<_>>();
+        for category in UiShowCategory::ALL {
+            commands.push((
+                PaletteCommand::Category(category),
+                tr_args("palette-switch-to", [("name", category.name().into())]),
+                tr("palette-category"),
+            ));
+        }
+        for smart_filter in self.shows.smart_filters() {
+            commands.push((
+                PaletteCommand::SmartFilter(smart_filter.id),
+                tr_args(
+                    "palette-apply",
+                    [("name", smart_filter.name.as_str().into())],
+                ),
+                tr("palette-filter"),
+            ));
+        }
+        for profile in self.shows.profiles() {
+            commands.push((
+                PaletteCommand::Profile(profile.id),
+                tr_args(
+                    "palette-switch-to",
+                    [("name", profile.name.as_str().into())],
+                ),
+                tr("palette-profile"),
+            ));
+        }
+
+        let query = search_key(query.trim());
+        let matcher = FuzzyMatcher::new(&query);
+
+        let mut matches = Vec::new();
+        for (command, label, detail) in commands {
+            if let Some(fuzzy_match) = matcher.find(&search_key(&label)) {
+                let highlights = original_positions(&label, &fuzzy_match.positions);
+                matches.push((
+                    fuzzy_match.score,
+                    PaletteMatch {
+                        command,
+                        label,
+                        detail,
+                        highlights,
+                    },
+                ));
+            }
+        }
+        for show in self.shows.all_shows() {
+            if let Some(fuzzy_match) = matcher.find(show.search_key()) {
+                let label = show.name().as_str().to_owned();
+                let highlights = original_positions(&label, &fuzzy_match.positions);
+                matches.push((
+                    fuzzy_match.score,
+                    PaletteMatch {
+                        command: PaletteCommand::Show(label.to_owned()),
+                        label,
+                        detail: tr("palette-show"),
+                        highlights,
+                    },
+                ));
+            }
+        }
+
+        // Best matches first, ties keep commands ahead of shows.
+        matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        matches
+            .into_iter()
+            .take(PALETTE_MATCHES)
+            .map(|(_, palette_match)| palette_match)
+            .collect()
+    }
+
+    fn run_command(&mut self, ctx: &egui::Context, command: PaletteCommand) {
+        match command {
+            PaletteCommand::Action(action) => self.perform(ctx, action),
+            PaletteCommand::Category(category) => {
+                *self.shows.current_category() = category;
+                self.shows.update_category();
+            }
+            PaletteCommand::SmartFilter(id) => self.shows.apply_smart_filter(id),
+            PaletteCommand::Profile(id) => self.shows.switch_profile(id),
+            PaletteCommand::Show(name) => {
+                if let Some(ui_index) = self.shows.reveal(&name) {
+                    self.go_to_show(ui_index);
+                    self.focused_row = Some(ui_index);
+                }
+            }
+        }
+    }
+
+    fn command_palette(&mut self, ui: &mut egui::Ui) {
+        let stale_query = self
+            .palette
+            .as_ref()
+            .filter(|palette| palette.matched_query.as_ref() != Some(&palette.query))
+            .map(|palette| palette.query.to_owned());
+        if let Some(query) = stale_query {
+            let matches = self.palette_matches(ui.ctx(), &query);
+            if let Some(palette) = &mut self.palette {
+                palette.matches = matches;
+                palette.matched_query = Some(query);
+            }
+        }
+        self.show_command_palette(ui);
+    }
+
+    fn show_command_palette(&mut self, ui: &mut egui::Ui) {
+        let Some(palette) = &mut self.palette else {
+            return;
+        };
+        let matches = &palette.matches;
+
+        // Move through the matches before the text box gets to the keys.
+        let (up, down, enter) = ui.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+            )
+        });
+        if up {
+            palette.selected = palette.selected.saturating_sub(1);
+        }
+        if down {
+            palette.selected += 1;
+        }
+        palette.selected = usize::min(palette.selected, matches.len().saturating_sub(1));
+
+        let mut chosen = enter.then_some(palette.selected);
+        let modal = egui::Modal::new(egui::Id::new("command_palette")).show(ui.ctx(), |ui| {
+            ui.set_width(TEXT_LABEL_WIDTH * 4f32);
+
+            let query_box = ui.add(
+                egui::TextEdit::singleline(&mut palette.query)
+                    .hint_text(tr("palette-hint"))
+                    .desired_width(f32::INFINITY),
+            );
+            query_box.request_focus();
+            if query_box.changed() {
+                palette.selected = 0;
+            }
+
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .max_height(ui.ctx().content_rect().height() / 2f32)
+                .show(ui, |ui| {
+                    egui::Grid::new("command_palette_grid").show(ui, |ui| {
+                        for (index, palette_match) in matches.iter().enumerate() {
+                            let selected = index == palette.selected;
+                            let row = ui.add(egui::Button::selectable(
+                                selected,
+                                highlighted_name(
+                                    ui,
+                                    &palette_match.label,
+                                    &palette_match.highlights,
+                                ),
+                            ));
+                            if selected && (up || down) {
+                                row.scroll_to_me(None);
+                            }
+                            if row.clicked() {
+                                chosen = Some(index);
+                            }
+                            ui.weak(&palette_match.detail);
+                            ui.end_row();
+                        }
+                    });
+                });
+        });
+
+        let command = chosen
+            .and_then(|index| matches.get(index))
+            .map(|palette_match| palette_match.command.to_owned());
+        if command.is_some() || modal.should_close() {
+            self.palette = None;
+        }
+        if let Some(command) = command {
+            self.run_command(ui.ctx(), command);
+        }
+    }
+
+    // Episodes per period as a bar chart, with as many of the periods
+    // labelled below it as fit.
+    fn episodes_chart(
+        ui: &mut egui::Ui,
+        id: &str,
+        title: egui::Id,
+        episodes_per_period: &[(String, i64)],
+        period_text: fn(&str) -> String,
+    ) {
+        const CHART_HEIGHT: f32 = 120f32;
+
+        let labels: Vec<String> = episodes_per_period
+            .iter()
+            .map(|(period, _)| period_text(period))
+            .collect();
+        let bars = episodes_per_period
+            .iter()
+            .zip(&labels)
+            .enumerate()
+            .map(|(index, ((_, episodes), label))| {
+                egui_plot::Bar::new(index as f64, *episodes as f64)
+                    .name(label)
+                    .fill(ui.visuals().selection.bg_fill)
+            })
+            .collect();
+        let bar_chart = egui_plot::BarChart::new(id, bars)
+            .width(0.7)
+            .element_formatter(Box::new(|bar, _| {
+                tr_args(
+                    "statistics-period-episodes",
+                    [
+                        ("period", bar.name.as_str().into()),
+                        ("count", (bar.value as i64).into()),
+                    ],
+                )
+            }));
+        // A fixed chart, the periods are all there is to see.
+        let chart = egui_plot::Plot::new(ui.id().with(id))
+            .height(CHART_HEIGHT)
+            .allow_zoom(false)
+            .allow_drag(false)
+            .allow_scroll(false)
+            .allow_boxed_zoom(false)
+            .allow_double_click_reset(false)
+            .show_x(false)
+            .show_y(false)
+            .include_y(0f64)
+            .x_axis_formatter(|mark, _| {
+                if mark.value.fract() == 0f64 && mark.value >= 0f64 {
+                    labels.get(mark.value as usize).cloned().unwrap_or_default()
+                } else {
+                    String::new()
+                }
+            })
+            .y_axis_formatter(|mark, _| {
+                if mark.value.fract() == 0f64 {
+                    i18n::format_number(mark.value as i64)
+                } else {
+                    String::new()
+                }
+            })
+            .show(ui, |plot| plot.bar_chart(bar_chart))
+            .response;
+        // Screen readers get the bars as a list of counts.
+        chart.ctx.accesskit_node_builder(chart.id, |node| {
+            node.set_role(egui::accesskit::Role::Image);
+            node.push_labelled_by(title.accesskit_id());
+        });
+        accessible_description(&chart, || {
+            episodes_per_period
+                .iter()
+                .zip(&labels)
+                .map(|((_, episodes), label)| {
+                    tr_args(
+                        "statistics-period-episodes",
+                        [
+                            ("period", label.as_str().into()),
+                            ("count", (*episodes).into()),
+                        ],
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+    }
+
+    fn statistics_window(&mut self, ui: &mut egui::Ui) {
+        let Some(statistics) = &self.statistics else {
+            return;
+        };
+
+        let mut open = true;
+        let mut refresh = false;
+        egui::Window::new(tr("statistics"))
+            .id(egui::Id::new(STATISTICS_ID))
+            .open(&mut open)
+            .show(ui.ctx(), |ui| {
+                egui::Grid::new("statistics_grid").show(ui, |ui| {
+                    for (category, count) in
+                        ShowCategory::ALL.iter().zip(statistics.category_counts)
+                    {
+                        ui.label(tr_args("field-label", [("field", category.name().into())]));
+                        ui.label(i18n::format_number(count as i64));
+                        ui.end_row();
+                    }
+
+                    ui.label(tr_args(
+                        "field-label",
+                        [("field", tr("statistics-episodes-seen").into())],
+                    ));
+                    ui.label(i18n::format_number(statistics.episodes_seen));
+                    ui.end_row();
+
+                    ui.label(tr_args(
+                        "field-label",
+                        [("field", tr("statistics-average-rating").into())],
+                    ));
+                    ui.label(statistics.average_rating_text());
+                    ui.end_row();
+
+                    ui.label(tr_args(
+                        "field-label",
+                        [("field", tr("statistics-completion-rate").into())],
+                    ));
+                    ui.label(statistics.completion_rate_text());
+                    ui.end_row();
+                });
+
+                ui.separator();
+
+                ui.label(tr("statistics-top-tags"));
+                ui.horizontal_wrapped(|ui| {
+                    if statistics.top_tags.is_empty() {
+                        ui.weak(tr("statistics-no-tags"));
+                    }
+                    for (tag, count) in &statistics.top_tags {
+                        ui.label(tr_args(
+                            "statistics-tag",
+                            [("tag", tag.as_str().into()), ("count", (*count).into())],
+                        ));
+                    }
+                });
+
+                ui.separator();
+
+                let episodes_per_week_label = ui.label(tr("statistics-episodes-per-week"));
+                Vcc::episodes_chart(
+                    ui,
+                    "episodes_per_week",
+                    episodes_per_week_label.id,
+                    &statistics.episodes_per_week,
+                    i18n::format_date,
+                );
+                ui.separator();
+                let episodes_per_month_label = ui.label(tr("statistics-episodes-per-month"));
+                Vcc::episodes_chart(
+                    ui,
+                    "episodes_per_month",
+                    episodes_per_month_label.id,
+                    &statistics.episodes_per_month,
+                    i18n::format_month,
+                );
+                ui.separator();
+
+                refresh = ui.button(tr("statistics-refresh")).clicked();
+            });
+
+        if refresh {
+            self.statistics = Some(self.shows.statistics());
+        }
+        if !open {
+            self.statistics = None;
+        }
+    }
+
+    fn smart_filters(&mut self, ui: &mut egui::Ui) {
+        let active_smart_filter = self.shows.active_smart_filter();
+        let mut applied = None;
+        let mut removed = None;
+
+        for smart_filter in self.shows.smart_filters() {
+            let button = ui
+                .add(egui::Button::selectable(
+                    active_smart_filter == Some(smart_filter.id),
+                    &smart_filter.name,
+                ))
+                .on_hover_text(&smart_filter.search_term);
+            accessible_description(&button, || {
+                tr_args(
+                    "a11y-filter",
+                    [("search", smart_filter.search_term.as_str().into())],
+                )
+            });
+            if button.clicked() {
+                applied = Some(smart_filter.id);
+            }
+            // The context menu can't be opened from the keyboard.
+            if button.has_focus() && self.keymap.pressed(ui.ctx(), Action::RemoveFilter) {
+                removed = Some(smart_filter.id);
+            }
+            button.context_menu(|ui| {
+                if ui.button(tr("filter-delete")).clicked() {
+                    removed = Some(smart_filter.id);
+                }
+            });
+        }
+
+        if let Some(id) = applied {
+            self.shows.apply_smart_filter(id);
+        }
+        if let Some(id) = removed {
+            self.shows.remove_smart_filter(id);
+        }
+
+        ui.menu_button(tr("filter-save-menu"), |ui| {
+            let filter_name_label = ui.label(tr("filter-name-label"));
+            let filter_name_box = ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.smart_filter_name)
+                        .desired_width(TEXT_LABEL_WIDTH)
+                        .clip_text(false),
+                )
+                .labelled_by(filter_name_label.id);
+            let submitted =
+                filter_name_box.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+            if ui.button(tr("save")).clicked() || submitted {
+                self.shows.save_smart_filter(&self.smart_filter_name);
+                self.smart_filter_name.clear();
+                ui.close();
+            }
+        })
+        .response
+        .on_hover_text(tr("filter-save-hint"));
+    }
+
+    fn sort_menu(&mut self, ui: &mut egui::Ui) {
+        ui.menu_button(tr("sorting"), |ui| {
+            let mut sort_options = self.shows.sort_options().to_owned();
+
+            ui.checkbox(
+                &mut sort_options.ignore_articles,
+                tr("sorting-ignore-articles"),
+            );
+
+            let articles_label = ui.label(tr("sorting-articles-label"));
+            let articles_box = ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.articles)
+                        .desired_width(TEXT_LABEL_WIDTH * 2f32),
+                )
+                .labelled_by(articles_label.id)
+                .on_hover_text(tr("sorting-articles-hint"));
+            if articles_box.lost_focus() {
+                sort_options.articles = SortOptions::parse_articles(&self.articles);
+                self.articles = sort_options.articles_text();
+            }
+
+            self.shows.set_sort_options(sort_options);
+        });
+    }
+
+    fn open_settings(&mut self, ctx: &egui::Context) {
+        // Ctrl+Plus and Ctrl+Minus zoom as well.
+        self.ui_scale = ctx.zoom_factor();
+        self.settings_open = true;
+    }
+
+    fn settings_window(&mut self, ui: &mut egui::Ui) {
+        if !self.settings_open {
+            return;
+        }
+
+        let ctx = ui.ctx().to_owned();
+        let mut settings = self.settings.clone();
+        egui::Window::new(tr("settings"))
+            .id(egui::Id::new(SETTINGS_ID))
+            .open(&mut self.settings_open)
+            .resizable(false)
+            .show(&ctx, |ui| {
+                egui::Grid::new("settings_grid")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        let language_label = ui.label(tr("settings-language"));
+                        egui::ComboBox::from_id_salt("language")
+                            .selected_text(settings.language.name())
+                            .show_ui(ui, |ui| {
+                                for language in Language::ALL {
+                                    ui.selectable_value(
+                                        &mut settings.language,
+                                        language,
+                                        language.name(),
+                                    );
+                                }
+                            })
+                            .response
+                            .labelled_by(language_label.id);
+                        ui.end_row();
+
+                        ui.label(tr("settings-theme"));
+                        let mut theme = ctx.options(|options| options.theme_preference);
+                        ui.horizontal(|ui| {
+                            for (preference, name) in [
+                                (egui::ThemePreference::System, tr("settings-theme-system")),
+                                (egui::ThemePreference::Light, tr("settings-theme-light")),
+                                (egui::ThemePreference::Dark, tr("settings-theme-dark")),
+                            ] {
+                                ui.radio_value(&mut theme, preference, name);
+                            }
+                        });
+                        ctx.set_theme(theme);
+                        ui.end_row();
+
+                        let scale_label = ui.label(tr("settings-scale"));
+                        let scale = ui
+                            .add(
+                                egui::Slider::new(&mut self.ui_scale, 0.5..=3.0)
+                                    .step_by(0.05)
+                                    .fixed_decimals(2),
+                            )
+                            .labelled_by(scale_label.id);
+                        if scale.drag_stopped() || (scale.changed() && !scale.dragged()) {
+                            ctx.set_zoom_factor(self.ui_scale);
+                        }
+                        ui.end_row();
+
+                        let font_label = ui.label(tr("settings-font"));
+                        ui.vertical(|ui| {
+                            egui::ComboBox::from_id_salt("font")
+                                .selected_text(settings.font.name())
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(
+                                        &mut settings.font,
+                                        Font::Default,
+                                        Font::Default.name(),
+                                    );
+                                    ui.selectable_value(
+                                        &mut settings.font,
+                                        Font::Monospace,
+                                        Font::Monospace.name(),
+                                    );
+                                    let file = Font::File(self.font_path.to_owned());
+                                    let file_name = file.name();
+                                    ui.selectable_value(&mut settings.font, file, file_name);
+                                })
+                                .response
+                                .labelled_by(font_label.id);
+
+                            if matches!(settings.font, Font::File(_)) {
+                                ui.horizontal(|ui| {
+                                    let path_box = ui.add(
+                                        egui::TextEdit::singleline(&mut self.font_path)
+                                            .hint_text(tr("settings-font-path-hint"))
+                                            .desired_width(TEXT_LABEL_WIDTH * 2f32)
+                                            .clip_text(false),
+                                    );
+                                    let entered = path_box.lost_focus()
+                                        && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                                    if ui.button(tr("settings-font-load")).clicked() || entered {
+                                        settings.font = Font::File(self.font_path.to_owned());
+                                    }
+                                });
+                            }
+
+                            if let Some(font_error) = &self.font_error {
+                                ui.colored_label(ui.visuals().error_fg_color, font_error);
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label(tr("settings-rows"));
+                        ui.horizontal(|ui| {
+                            for density in Density::ALL {
+                                ui.radio_value(&mut settings.density, density, density.name());
+                            }
+                        });
+                        ui.end_row();
+                    });
+
+                ui.separator();
+
+                if ui
+                    .button(tr("settings-reset-layout"))
+                    .on_hover_text(tr("settings-reset-layout-hint"))
+                    .clicked()
+                {
+                    Vcc::reset_layout(&ctx);
+                }
+            });
+
+        if settings != self.settings {
+            self.font_error = settings.apply(&ctx).err();
+            self.settings = settings;
+        }
+    }
+
+    // Bulk actions on the selected shows, shown while there are any.
+    fn selection_bar(&mut self, ui: &mut egui::Ui) {
+        let selection_count = self.shows.selection_count();
+        if selection_count == 0 {
+            return;
+        }
+
+        ui.horizontal_wrapped(|ui| {
+            ui.strong(tr_args(
+                "selection-count",
+                [("count", selection_count.into())],
+            ));
+            if ui
+                .button(tr_args(
+                    "selection-select-all",
+                    [("count", self.shows.shows_count().into())],
+                ))
+                .clicked()
+            {
+                self.shows.select_all();
+            }
+            if ui.button(tr("selection-clear")).clicked() {
+                self.shows.clear_selection();
+            }
+
+            ui.separator();
+
+            ui.label(tr("selection-move-to"));
+            for category in ShowCategory::ALL {
+                if ui.button(category.name()).clicked() {
+                    self.shows.set_selected_category(category);
+                }
+            }
+
+            ui.separator();
+
+            let tag_box = ui.add(
+                egui::TextEdit::singleline(&mut self.selection_tag)
+                    .hint_text(tr("selection-tag-hint"))
+                    .desired_width(NUMBER_LABEL_WIDTH * 2f32)
+                    .clip_text(false),
+            );
+            accessible_label(&tag_box, || tr("selection-tag-hint"));
+            let has_tag = !self.selection_tag.trim().is_empty();
+            if ui
+                .add_enabled(has_tag, egui::Button::new(tr("selection-add-tag")))
+                .clicked()
+            {
+                self.shows.add_selected_tag(&self.selection_tag);
+            }
+            if ui
+                .add_enabled(has_tag, egui::Button::new(tr("selection-remove-tag")))
+                .clicked()
+            {
+                self.shows.remove_selected_tag(&self.selection_tag);
+            }
+
+            ui.separator();
+
+            if ui
+                .add(Vcc::delete_button(ui, tr("selection-delete")))
+                .clicked()
+            {
+                self.pending_delete = Some(PendingDelete::Selection(selection_count));
+            }
+
+            ui.menu_button(tr("selection-export"), |ui| {
+                let export_label = ui.label(tr("selection-export-label"));
+                let export_box = ui
+                    .add(
+                        egui::TextEdit::singleline(&mut self.export_path)
+                            .hint_text(tr("selection-export-hint"))
+                            .desired_width(TEXT_LABEL_WIDTH)
+                            .clip_text(false),
+                    )
+                    .labelled_by(export_label.id);
+                let submitted =
+                    export_box.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+                if ui.button(tr("selection-export-save")).clicked() || submitted {
+                    let path = self.export_path.trim();
+                    self.export_result = Some(
+                        self.shows
+                            .export_selected(path)
+                            .map(|count| {
+                                tr_args(
+                                    "selection-exported",
+                                    [("count", count.into()), ("path", path.into())],
+                                )
+                            })
+                            .map_err(|error| {
+                                tr_args(
+                                    "selection-export-error",
+                                    [("path", path.into()), ("error", error.into())],
+                                )
+                            }),
+                    );
+                }
+
+                match &self.export_result {
+                    Some(Ok(message)) => {
+                        ui.label(message);
+                    }
+                    Some(Err(error)) => {
+                        ui.colored_label(ui.visuals().error_fg_color, error);
+                    }
+                    None => (),
+                }
+            });
+        });
+
+        ui.separator();
+    }
+
+    fn rows(&mut self, ui: &mut egui::Ui) {
+        let column_sort = self.shows.column_sort();
+        let modifications = &mut self.accumulated_modifications;
+        let details_request = &mut self.details_request;
+        let pending_delete = &mut self.pending_delete;
+
+        let groups = self.shows.groups();
+        let manual_order = self.shows.manual_order();
+
+        let focused_row = self
+            .focused_row
+            .filter(|row| *row < self.shows.shows_count());
+
+        if !self.shows.continuous() {
+            let page_range = self.shows.page_range();
+            let headers = groups
+                .map(|groups| {
+                    groups.headers(
+                        page_range.clone(),
+                        self.shows.page() == self.shows.page_count(),
+                    )
+                })
+                .unwrap_or_default();
+            let selected_rows = page_range
+                .clone()
+                .filter(|row| self.shows.is_selected(*row))
+                .collect::<Vec<_>>();
+            let row_color = row_color(
+                focused_row.and_then(|row| grid_row(row, page_range.start, &headers)),
+                selected_rows
+                    .iter()
+                    .filter_map(|row| grid_row(*row, page_range.start, &headers))
+                    .collect(),
+            );
+            let page_selected = !page_range.is_empty() && selected_rows.len() == page_range.len();
+            egui::Grid::new("display_show_grid")
+                .with_row_color(row_color)
+                .show(ui, |ui| {
+                    Vcc::header_row(ui, column_sort, page_range, page_selected, modifications);
+                    let mut headers = headers.into_iter().peekable();
+                    for (index, show, highlights) in self.shows.iter_mut() {
+                        while let Some((_, category)) =
+                            headers.next_if(|(start, _)| *start <= index)
+                        {
+                            Vcc::group_header(ui, groups, category, modifications);
+                        }
+                        Vcc::select_cell(
+                            ui,
+                            index,
+                            show.name().as_str(),
+                            selected_rows.contains(&index),
+                            manual_order && show.category == ShowCategory::PlanToWatch,
+                            modifications,
+                        );
+                        Vcc::show_row(
+                            ui,
+                            index,
+                            show,
+                            highlights,
+                            modifications,
+                            details_request,
+                            pending_delete,
+                        );
+                    }
+                    for (_, category) in headers {
+                        Vcc::group_header(ui, groups, category, modifications);
+                    }
+                });
+            ui.separator();
+            return;
+        }
+
+        // Only lay out the rows scrolled into view, the header row stays on
+        // top of them. Group headers take a row each.
+        let row_height = ui.spacing().interact_size.y;
+        let group_rows = groups.map_or(0, |groups| groups.starts.len());
+        let total_rows = self.shows.shows_count() + group_rows + 1;
+        let mut scroll_area = egui::ScrollArea::vertical().auto_shrink(false);
+        if let Some(row) = self.scroll_to_row.take() {
+            // Scrolled to the top, a row sits just below the header.
+            let row = groups.map_or(row, |groups| row + groups.headers_before(row));
+            scroll_area = scroll_area
+                .vertical_scroll_offset(row as f32 * (row_height + ui.spacing().item_spacing.y));
+        }
+        let visible_rows = &mut self.visible_rows;
+        scroll_area.show_rows(ui, row_height, total_rows, |ui, row_range| {
+            let grid_range = row_range.start..row_range.end.saturating_sub(1);
+            let (show_range, headers) = match groups {
+                Some(groups) => groups.rows(grid_range),
+                None => (grid_range, Vec::new()),
+            };
+            *visible_rows = show_range.clone();
+            let selected_rows = show_range
+                .clone()
+                .filter(|row| self.shows.is_selected(*row))
+                .collect::<Vec<_>>();
+            let row_color = row_color(
+                focused_row.and_then(|row| grid_row(row, show_range.start, &headers)),
+                selected_rows
+                    .iter()
+                    .filter_map(|row| grid_row(*row, show_range.start, &headers))
+                    .collect(),
+            );
+            let shown_rows = usize::min(show_range.end, self.shows.shows_count())
+                .saturating_sub(show_range.start);
+            let page_selected = shown_rows > 0 && selected_rows.len() == shown_rows;
+            egui::Grid::new("display_show_grid")
+                .with_row_color(row_color)
+                .show(ui, |ui| {
+                    Vcc::header_row(
+                        ui,
+                        column_sort,
+                        show_range.clone(),
+                        page_selected,
+                        modifications,
+                    );
+                    let mut headers = headers.into_iter().peekable();
+                    for (index, show, highlights) in self.shows.iter_mut_range(show_range) {
+                        while let Some((_, category)) =
+                            headers.next_if(|(start, _)| *start <= index)
+                        {
+                            Vcc::group_header(ui, groups, category, modifications);
+                        }
+                        Vcc::select_cell(
+                            ui,
+                            index,
+                            show.name().as_str(),
+                            selected_rows.contains(&index),
+                            manual_order && show.category == ShowCategory::PlanToWatch,
+                            modifications,
+                        );
+                        Vcc::show_row(
+                            ui,
+                            index,
+                            show,
+                            highlights,
+                            modifications,
+                            details_request,
+                            pending_delete,
+                        );
+                    }
+                    for (_, category) in headers {
+                        Vcc::group_header(ui, groups, category, modifications);
+                    }
+                });
+        });
+    }
+
+    fn header_row(
+        ui: &mut egui::Ui,
+        column_sort: Option<ColumnSort>,
+        page_range: Range<usize>,
+        page_selected: bool,
+        modifications: &mut AccumulatedModifications,
+    ) {
+        let mut selected = page_selected;
+        let page_checkbox = ui
+            .checkbox(&mut selected, "")
+            .on_hover_text(tr("selection-page"));
+        accessible_label(&page_checkbox, || tr("selection-page"));
+        if page_checkbox.changed() {
+            modifications.push(Box::new(move |shows: &mut ShowsView| {
+                shows.select_range(page_range, selected);
+            }));
+        }
+        ui.label("");
+        for (column, column_name) in [
+            (SortColumn::Name, tr("column-name")),
+            (SortColumn::Season, tr("column-season")),
+            (SortColumn::Episodes, tr("column-episodes")),
+            (SortColumn::Rating, tr("column-rating")),
+            (SortColumn::LastUpdated, tr("column-last-updated")),
+        ] {
+            let header = match column_sort {
+                Some(ColumnSort {
+                    column: sorted_column,
+                    descending,
+                }) if sorted_column == column => {
+                    format!("{} {}", column_name, if descending { "⏷" } else { "⏶" })
+                }
+                _ => column_name.to_owned(),
+            };
+            let sort_hint = tr_args(
+                "column-sort-hint",
+                [("column", column_name.as_str().into())],
+            );
+            let header_button = ui
+                .add(egui::Button::selectable(
+                    column_sort.is_some_and(|column_sort| column_sort.column == column),
+                    header,
+                ))
+                .on_hover_text(&sort_hint);
+            // The arrow would be read out by its name.
+            accessible_label(&header_button, || column_name);
+            accessible_description(&header_button, || match column_sort {
+                Some(ColumnSort {
+                    column: sorted_column,
+                    descending,
+                }) if sorted_column == column => tr(if descending {
+                    "a11y-sorted-descending"
+                } else {
+                    "a11y-sorted-ascending"
+                }),
+                _ => sort_hint,
+            });
+            if header_button.clicked() {
+                modifications.push(Box::new(move |shows: &mut ShowsView| {
+                    shows.sort_by(column);
+                }));
+            }
+        }
+        ui.end_row();
+    }
+
+    // A category's header in the All view, which collapses or expands it.
+    fn group_header(
+        ui: &mut egui::Ui,
+        groups: Option<Groups>,
+        category: usize,
+        modifications: &mut AccumulatedModifications,
+    ) {
+        let Some(groups) = groups else {
+            return;
+        };
+
+        let collapsed = groups.collapsed[category];
+        let group_text = tr_args(
+            "category-button",
+            [
+                ("category", ShowCategory::ALL[category].name().into()),
+                ("count", groups.counts[category].into()),
+            ],
+        );
+        ui.label("");
+        ui.label("");
+        let header_button = ui
+            .add(egui::Button::selectable(
+                false,
+                egui::RichText::new(format!(
+                    "{} {}",
+                    if collapsed { "⏵" } else { "⏷" },
+                    group_text
+                ))
+                .strong(),
+            ))
+            .on_hover_text(if collapsed {
+                tr("group-expand")
+            } else {
+                tr("group-collapse")
+            });
+        header_button
+            .ctx
+            .accesskit_node_builder(header_button.id, |node| {
+                node.set_label(group_text);
+                node.set_expanded(!collapsed);
+            });
+        if header_button.clicked() {
+            modifications.push(Box::new(move |shows: &mut ShowsView| {
+                shows.toggle_collapsed(category);
+            }));
+        }
+        ui.end_row();
+    }
+
+    // The checkbox picking a show for the bulk actions, and the handle it's
+    // dragged by. In the priority order, shows are dropped onto the row
+    // they're to go before or after.
+    fn select_cell(
+        ui: &mut egui::Ui,
+        index: usize,
+        name: &str,
+        selected: bool,
+        reorderable: bool,
+        modifications: &mut AccumulatedModifications,
+    ) {
+        let cell = ui.horizontal(|ui| {
+            let mut selected = selected;
+            let select_checkbox = ui
+                .checkbox(&mut selected, "")
+                .on_hover_text(tr("selection-range-hint"));
+            accessible_label(&select_checkbox, || {
+                tr_args("a11y-select", [("name", name.into())])
+            });
+            if select_checkbox.changed() {
+                let extend = ui.input(|i| i.modifiers.shift);
+                modifications.push(Box::new(move |shows: &mut ShowsView| {
+                    shows.select(index, selected, extend);
+                }));
+            }
+
+            let handle = ui
+                .dnd_drag_source(
+                    egui::Id::new("drag_show").with(name),
+                    DraggedShow {
+                        name: name.to_owned(),
+                    },
+                    |ui| ui.label("☰"),
+                )
+                .response
+                .on_hover_text(tr("drag-hint"));
+            accessible_label(&handle, || tr_args("a11y-drag", [("name", name.into())]));
+        });
+
+        if !reorderable {
+            return;
+        }
+        let row = egui::Rect::from_x_y_ranges(
+            cell.response.rect.left()..=ui.clip_rect().right(),
+            cell.response
+                .rect
+                .expand(ui.spacing().item_spacing.y / 2f32)
+                .y_range(),
+        );
+        let Some(dragged) = egui::DragAndDrop::payload::<DraggedShow>(ui.ctx()) else {
+            return;
+        };
+        let Some(pointer) = ui.ctx().pointer_interact_pos() else {
+            return;
+        };
+        if dragged.name == name || !row.contains(pointer) {
+            return;
+        }
+
+        let after = pointer.y > row.center().y;
+        ui.painter().hline(
+            row.x_range(),
+            if after { row.bottom() } else { row.top() },
+            ui.visuals().selection.stroke,
+        );
+        if ui.input(|i| i.pointer.any_released()) {
+            egui::DragAndDrop::clear_payload(ui.ctx());
+            let (dragged, target) = (dragged.name.to_owned(), name.to_owned());
+            modifications.push(Box::new(move |shows: &mut ShowsView| {
+                shows.move_in_priority(&dragged, &target, after);
+            }));
+        }
+    }
+
+    fn show_row(
+        ui: &mut egui::Ui,
+        index: usize,
+        show: &mut DisplayShow,
+        highlights: &[usize],
+        modifications: &mut AccumulatedModifications,
+        details_request: &mut Option<String>,
+        pending_delete: &mut Option<PendingDelete>,
+    ) {
+        let name = show.name().as_str().to_owned();
+        ui.horizontal(|ui| {
+            let delete_button = ui.add(Vcc::delete_button(ui, tr("row-delete")));
+            accessible_label(&delete_button, || {
+                tr_args("a11y-delete", [("name", name.as_str().into())])
+            });
+            if delete_button.clicked() {
+                *pending_delete = Some(PendingDelete::Show(name.to_owned()));
+            }
+            ui.separator();
+        });
+
+        ui.horizontal(|ui| {
+            ui.label(tr("name-label"));
+            let name_label = ui.add(
+                egui::Label::new(highlighted_name(ui, show.name().as_str(), highlights))
+                    .sense(egui::Sense::click()),
+            );
+            accessible_description(&name_label, || row_description(show));
+            let details_button = ui.small_button(tr("row-details"));
+            accessible_label(&details_button, || {
+                tr_args("a11y-details", [("name", name.as_str().into())])
+            });
+            if name_label.clicked() || details_button.clicked() {
+                *details_request = Some(name.to_owned());
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.separator();
+
+            let decrease_button = ui.button("-");
+            accessible_label(&decrease_button, || {
+                tr_args("a11y-previous-season", [("name", name.as_str().into())])
+            });
+            if decrease_button.clicked() && change_number(&mut show.season_number, |curr| curr - 1)
+            {
+                modifications.push(Box::new(move |shows: &mut ShowsView| {
+                    shows.update(index);
+                }));
+            }
+
+            let season_label = ui.label(tr("season-label"));
+            let season_number_textbox = ui
+                .add(
+                    egui::TextEdit::singleline(&mut show.season_number)
+                        .desired_width(NUMBER_LABEL_WIDTH)
+                        .clip_text(false),
+                )
+                .labelled_by(season_label.id);
+            accessible_label(&season_number_textbox, || {
+                tr_args("a11y-season", [("name", name.as_str().into())])
+            });
+
+            if season_number_textbox.changed()
+                && change_number(&mut show.season_number, |curr| curr)
+            {
+                modifications.push(Box::new(move |shows: &mut ShowsView| {
+                    shows.update(index);
+                }));
+            }
+
+            let increase_button = ui.button("+");
+            accessible_label(&increase_button, || {
+                tr_args("a11y-next-season", [("name", name.as_str().into())])
+            });
+            if increase_button.clicked() && change_number(&mut show.season_number, |curr| curr + 1)
+            {
+                modifications.push(Box::new(move |shows: &mut ShowsView| {
+                    shows.update(index);
+                }));
+            }
+
+            ui.separator();
+        });
+        ui.horizontal(|ui| {
+            let decrease_button = ui.button("-");
+            accessible_label(&decrease_button, || {
+                tr_args("a11y-fewer-episodes", [("name", name.as_str().into())])
+            });
+            if decrease_button.clicked() && change_number(&mut show.episodes_seen, |curr| curr - 1)
+            {
+                modifications.push(Box::new(move |shows: &mut ShowsView| {
+                    shows.update(index);
+                }));
+            }
+
+            let episodes_seen_label = ui.label(tr("episodes-label"));
+            let episodes_label_textbox = ui
+                .add(
+                    egui::TextEdit::singleline(&mut show.episodes_seen)
+                        .desired_width(NUMBER_LABEL_WIDTH)
+                        .clip_text(false),
+                )
+                .labelled_by(episodes_seen_label.id);
+            accessible_label(&episodes_label_textbox, || {
+                tr_args("a11y-episodes", [("name", name.as_str().into())])
+            });
+
+            if episodes_label_textbox.changed()
+                && change_number(&mut show.episodes_seen, |curr| curr)
+            {
+                modifications.push(Box::new(move |shows: &mut ShowsView| {
+                    shows.update(index);
+                }));
+            }
+
+            let increase_button = ui.button("+");
+            accessible_label(&increase_button, || {
+                tr_args("a11y-more-episodes", [("name", name.as_str().into())])
+            });
+            if increase_button.clicked() && change_number(&mut show.episodes_seen, |curr| curr + 1)
+            {
+                modifications.push(Box::new(move |shows: &mut ShowsView| {
+                    shows.update(index);
+                }));
+            }
+
+            ui.separator();
+        });
+        ui.horizontal(|ui| {
+            let rating_label = ui.label(tr("rating-label"));
+            let rating_textbox = ui
+                .add(
+                    egui::TextEdit::singleline(&mut show.rating)
+                        .hint_text("-")
+                        .desired_width(NUMBER_LABEL_WIDTH)
+                        .clip_text(false),
+                )
+                .labelled_by(rating_label.id)
+                .on_hover_text(tr_args("rating-hint", [("max", MAX_RATING.into())]));
+            accessible_label(&rating_textbox, || {
+                tr_args("a11y-rating", [("name", name.as_str().into())])
+            });
+            accessible_description(&rating_textbox, || {
+                tr_args("rating-hint", [("max", MAX_RATING.into())])
+            });
+
+            if rating_textbox.changed() && valid_rating(&show.rating) {
+                modifications.push(Box::new(move |shows: &mut ShowsView| {
+                    shows.update(index);
+                }));
+            }
+
+            ui.separator();
+        });
+        ui.label(last_updated_text(show.last_updated));
+        let category_label = ui.label(tr("category-label"));
+        let category_box = egui::ComboBox::from_id_salt(category_label.id)
+            .selected_text(show.category.name())
+            .show_ui(ui, |ui| {
+                let mut changed = false;
+                for category in ShowCategory::ALL {
+                    changed |= ui
+                        .selectable_value(&mut show.category, category, category.name())
+                        .changed();
+                }
+
+                if changed {
+                    modifications.push(Box::new(move |shows: &mut ShowsView| {
+                        shows.update(index);
+                    }));
+                }
+            });
+        accessible_label(&category_box.response, || {
+            tr_args("a11y-category", [("name", name.as_str().into())])
+        });
+        ui.end_row();
+    }
+
+    // Open the side panel on a show, keeping what was edited for the one
+    // before.
+    fn open_details(&mut self, name: String) {
+        self.save_details();
+        if self.details_name.as_ref() == Some(&name) {
+            return;
+        }
+        if let Some(show) = self.shows.show(&name) {
+            self.details = show.details.to_owned();
+            self.details_name = Some(name);
+            self.details_revision = None;
+        }
+    }
+
+    // Look up what the panel shows about its show again, once the shows
+    // have changed since.
+    fn refresh_details(&mut self, name: &str) {
+        let revision = self.shows.revision();
+        let in_place = self.details_ui_index.is_none_or(|ui_index| {
+            self.shows
+                .ui_show_mut(ui_index)
+                .is_some_and(|show| show.name().as_str() == name)
+        });
+        if self.details_revision == Some(revision) && in_place {
+            return;
+        }
+        self.details_revision = Some(revision);
+        self.details_ui_index = self.shows.ui_index(name);
+        self.details_priority_rank = self
+            .shows
+            .show(name)
+            .filter(|show| show.category == ShowCategory::PlanToWatch)
+            .and_then(|_| self.shows.priority_rank(name));
+        self.details_activity = self.shows.activity(name);
+    }
+
+    // The panel's details are saved as it moves on, rather than with a
+    // button.
+    fn save_details(&mut self) {
+        let Some(name) = &self.details_name else {
+            return;
+        };
+        if self
+            .shows
+            .show(name)
+            .is_some_and(|show| show.details != self.details)
+        {
+            self.shows.update_details(name, self.details.to_owned());
+        }
+    }
+
+    // A labelled number with buttons to step it, in two grid cells. True
+    // once it's changed to a valid one.
+    fn number_editor(ui: &mut egui::Ui, number: &mut String, field: &str) -> bool {
+        let label = ui.label(field);
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            let decrease_button = ui.button("-");
+            accessible_label(&decrease_button, || {
+                tr_args(
+                    "a11y-decrease",
+                    [("field", field.trim_end_matches(':').into())],
+                )
+            });
+            if decrease_button.clicked() {
+                changed |= change_number(number, |curr| curr - 1);
+            }
+            let textbox = ui
+                .add(
+                    egui::TextEdit::singleline(number)
+                        .desired_width(NUMBER_LABEL_WIDTH)
+                        .clip_text(false),
+                )
+                .labelled_by(label.id);
+            if textbox.changed() {
+                changed |= change_number(number, |curr| curr);
+            }
+            let increase_button = ui.button("+");
+            accessible_label(&increase_button, || {
+                tr_args(
+                    "a11y-increase",
+                    [("field", field.trim_end_matches(':').into())],
+                )
+            });
+            if increase_button.clicked() {
+                changed |= change_number(number, |curr| curr + 1);
+            }
+        });
+        changed
+    }
+
+    // Everything about one show, beside the grid. Its progress can only be
+    // changed while the show is in view, where the grid keeps its copy.
+    fn details_panel(&mut self, ui: &mut egui::Ui) {
+        let Some(name) = self.details_name.to_owned() else {
+            return;
+        };
+        if self.shows.show(&name).is_none() {
+            // Deleted since.
+            self.details_name = None;
+            return;
+        }
+        self.refresh_details(&name);
+        let ui_index = self.details_ui_index;
+        let priority_rank = self.details_priority_rank;
+        let activity = &self.details_activity;
+
+        // The grid's copy of the show is edited in place, keeping whatever is
+        // being typed. A show out of view is only shown.
+        let mut out_of_view = None;
+        let show = match ui_index {
+            Some(ui_index) => self
+                .shows
+                .ui_show_mut(ui_index)
+                .expect("Unable to find the show in view."),
+            None => out_of_view.insert(
+                self.shows
+                    .show(&name)
+                    .cloned()
+                    .expect("Unable to find the show."),
+            ),
+        };
+
+        let mut closed = false;
+        let mut changed = false;
+        let mut save = false;
+        egui::Panel::right(DETAILS_PANEL_ID)
+            .resizable(true)
+            .default_size(DETAILS_PANEL_WIDTH)
+            .min_size(DETAILS_PANEL_MIN_WIDTH)
+            .show_inside(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let close_button = ui.button("✕").on_hover_text(tr("details-close"));
+                    accessible_label(&close_button, || tr("details-close"));
+                    closed = close_button.clicked();
+                    ui.heading(name.as_str());
+                });
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .show(ui, |ui| {
+                        if ui_index.is_none() {
+                            ui.label(tr("details-out-of-view"));
+                        }
+                        ui.add_enabled_ui(ui_index.is_some(), |ui| {
+                            egui::Grid::new("show_details_grid").show(ui, |ui| {
+                                let category_label = ui.label(tr("category-label"));
+                                egui::ComboBox::from_id_salt(category_label.id)
+                                    .selected_text(show.category.name())
+                                    .show_ui(ui, |ui| {
+                                        for category in ShowCategory::ALL {
+                                            changed |= ui
+                                                .selectable_value(
+                                                    &mut show.category,
+                                                    category,
+                                                    category.name(),
+                                                )
+                                                .changed();
+                                        }
+                                    });
+                                ui.end_row();
+
+                                changed |= Vcc::number_editor(
+                                    ui,
+                                    &mut show.season_number,
+                                    &tr("season-label"),
+                                );
+                                ui.end_row();
+
+                                changed |= Vcc::number_editor(
+                                    ui,
+                                    &mut show.episodes_seen,
+                                    &tr("episodes-label"),
+                                );
+                                ui.end_row();
+
+                                let rating_label = ui.label(tr("rating-label"));
+                                let rating_textbox = ui
+                                    .add(
+                                        egui::TextEdit::singleline(&mut show.rating)
+                                            .hint_text("-")
+                                            .desired_width(NUMBER_LABEL_WIDTH)
+                                            .clip_text(false),
+                                    )
+                                    .labelled_by(rating_label.id)
+                                    .on_hover_text(tr_args(
+                                        "rating-hint",
+                                        [("max", MAX_RATING.into())],
+                                    ));
+                                changed |= rating_textbox.changed() && valid_rating(&show.rating);
+                                ui.end_row();
+
+                                ui.label(tr("details-last-updated"));
+                                ui.label(last_updated_text(show.last_updated));
+                                ui.end_row();
+
+                                if let Some(rank) = priority_rank {
+                                    ui.label(tr("details-priority"));
+                                    ui.label(tr_args("details-rank", [("rank", rank.into())]));
+                                    ui.end_row();
+                                }
+                            });
+                        });
+                        ui.separator();
+
+                        // Saved as the focus leaves each of them.
+                        let aliases_label = ui.label(tr("details-aliases"));
+                        save |= ui
+                            .add(
+                                egui::TextEdit::singleline(&mut self.details.aliases)
+                                    .hint_text(tr("details-list-hint"))
+                                    .desired_width(f32::INFINITY),
+                            )
+                            .labelled_by(aliases_label.id)
+                            .lost_focus();
+
+                        let tags_label = ui.label(tr("details-tags"));
+                        save |= ui
+                            .add(
+                                egui::TextEdit::singleline(&mut self.details.tags)
+                                    .hint_text(tr("details-list-hint"))
+                                    .desired_width(f32::INFINITY),
+                            )
+                            .labelled_by(tags_label.id)
+                            .lost_focus();
+
+                        let notes_label = ui.label(tr("details-notes"));
+                        save |= ui
+                            .add(
+                                egui::TextEdit::multiline(&mut self.details.notes)
+                                    .desired_rows(NOTES_ROWS)
+                                    .desired_width(f32::INFINITY),
+                            )
+                            .labelled_by(notes_label.id)
+                            .lost_focus();
+                        ui.separator();
+
+                        ui.label(egui::RichText::new(tr("details-activity")).strong());
+                        if activity.is_empty() {
+                            ui.label(tr("details-no-activity"));
+                        }
+                        egui::Grid::new("show_activity_grid")
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for entry in activity {
+                                    ui.label(tr_args(
+                                        "details-activity-time",
+                                        [
+                                            ("date", i18n::format_date(&entry.date).into()),
+                                            ("time", entry.time.as_str().into()),
+                                        ],
+                                    ));
+                                    ui.label(activity_text(entry));
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            });
+
+        // Saved once it's valid.
+        if let Some(ui_index) = ui_index.filter(|_| changed) {
+            self.shows.update(ui_index);
+        }
+        if save || closed {
+            self.save_details();
+        }
+        if closed {
+            self.details_name = None;
+        }
+    }
+
+    // A field of the add form, with why it's invalid under it.
+    fn adder_field(
+        ui: &mut egui::Ui,
+        label: String,
+        text_edit: egui::TextEdit,
+        problem: Option<&String>,
+    ) -> egui::Response {
+        ui.vertical(|ui| {
+            let response = ui
+                .horizontal(|ui| {
+                    let label = ui.label(label);
+                    ui.add(text_edit).labelled_by(label.id)
+                })
+                .inner;
+            if let Some(problem) = problem {
+                ui.label(
+                    egui::RichText::new(problem)
+                        .small()
+                        .color(ui.visuals().error_fg_color),
+                );
+                accessible_description(&response, || problem.to_owned());
+            }
+            response
+        })
+        .inner
+    }
+
+    fn add(&mut self, ui: &mut egui::Ui) {
+        let problems = AdderProblems::new(&self.adder, &self.shows);
+        // Lookalikes are only looked for again once the name or the shows
+        // change.
+        let similar_to = (
+            self.adder.name.trim().to_owned(),
+            self.shows.category_count(UiShowCategory::All),
+        );
+        if self.similar_to != similar_to {
+            self.similar_shows = if similar_to.0.is_empty() {
+                Vec::new()
+            } else {
+                self.shows.similar_shows(&similar_to.0)
+            };
+            self.similar_to = similar_to;
+        }
+        // An empty name is only complained about once the form is being
+        // filled in, not on a fresh one.
+        let name_problem = problems
+            .name
+            .as_ref()
+            .filter(|_| self.adder_edited || !self.adder.name.is_empty());
+
+        ui.label(tr("add-title"));
+        let mut enter = false;
+        let mut edited = false;
+        let mut submitted_with = |response: egui::Response| {
+            edited |= response.changed();
+            response.lost_focus() && response.ctx.input(|i| i.key_pressed(egui::Key::Enter))
+        };
+        ui.horizontal_top(|ui| {
+            enter |= submitted_with(Vcc::adder_field(
+                ui,
+                tr("name-label"),
+                egui::TextEdit::singleline(&mut self.adder.name)
+                    .id(egui::Id::new(ADD_NAME_ID))
+                    .desired_width(TEXT_LABEL_WIDTH)
+                    .clip_text(false),
+                name_problem,
+            ));
+
+            ui.separator();
+
+            enter |= submitted_with(Vcc::adder_field(
+                ui,
+                tr("season-label"),
+                egui::TextEdit::singleline(&mut self.adder.season_number)
+                    .desired_width(NUMBER_LABEL_WIDTH)
+                    .clip_text(false),
+                problems.season_number.as_ref(),
+            ));
+
+            ui.separator();
+
+            enter |= submitted_with(Vcc::adder_field(
+                ui,
+                tr("episodes-label"),
+                egui::TextEdit::singleline(&mut self.adder.episodes_seen)
+                    .desired_width(NUMBER_LABEL_WIDTH)
+                    .clip_text(false),
+                problems.episodes_seen.as_ref(),
+            ));
+
+            ui.separator();
+
+            enter |= submitted_with(
+                Vcc::adder_field(
+                    ui,
+                    tr("rating-label"),
+                    egui::TextEdit::singleline(&mut self.adder.rating)
+                        .hint_text("-")
+                        .desired_width(NUMBER_LABEL_WIDTH)
+                        .clip_text(false),
+                    problems.rating.as_ref(),
+                )
+                .on_hover_text(tr_args("rating-hint", [("max", MAX_RATING.into())])),
+            );
+
+            ui.separator();
+
+            let category_label = ui.label(tr("category-label"));
+            egui::ComboBox::from_id_salt(category_label.id)
+                .selected_text(self.adder.category.name())
+                .show_ui(ui, |ui| {
+                    for category in ShowCategory::ALL {
+                        ui.selectable_value(&mut self.adder.category, category, category.name());
+                    }
+                });
+        });
+        self.adder_edited |= edited;
+
+        // Either way the show is already there, so offer to go to it.
+        let mut go_to = None;
+        if let Some(existing) = &problems.duplicate {
+            ui.horizontal(|ui| {
+                if ui.link(tr("add-go-to-existing")).clicked() {
+                    go_to = Some(existing.to_owned());
+                }
+            });
+        } else if !self.similar_shows.is_empty() {
+            ui.horizontal_wrapped(|ui| {
+                ui.label(
+                    egui::RichText::new(tr("add-similar-warning"))
+                        .color(ui.visuals().warn_fg_color),
+                );
+                for name in &self.similar_shows {
+                    if ui.link(name.as_str()).clicked() {
+                        go_to = Some(name.to_owned());
+                    }
+                }
+            });
+        }
+        if let Some(row) = go_to.and_then(|name| self.shows.reveal(&name)) {
+            self.focused_row = Some(row);
+            self.go_to_show(row);
+        }
+
+        // What keeps the button disabled, including an empty name that isn't
+        // complained about yet.
+        let all_problems = [
+            &problems.name,
+            &problems.season_number,
+            &problems.episodes_seen,
+            &problems.rating,
+        ]
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join("\n");
+        let add_button = ui
+            .add_enabled(problems.valid(), egui::Button::new(tr("add")))
+            .on_disabled_hover_text(&all_problems);
+        accessible_description(&add_button, || all_problems.to_owned());
+        if !add_button.clicked() && !enter {
+            return;
+        }
+        if !problems.valid() {
+            self.adder_edited = true;
+            return;
+        }
+
+        let mut owned_adder = self.adder.to_owned();
+        owned_adder.name = owned_adder.name.trim().to_owned();
+        self.accumulated_modifications
+            .push(Box::new(move |shows: &mut ShowsView| {
+                shows.add(owned_adder);
+            }));
+        self.adder.clear();
+        self.adder_edited = false;
+        // Ready for the next show.
+        ui.memory_mut(|memory| memory.request_focus(egui::Id::new(ADD_NAME_ID)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Language;
+
+    // The labels and descriptions screen readers get for a row of the show.
+    fn row_accessibility(
+        language: Language,
+        show: &mut DisplayShow,
+    ) -> Vec<(Option<String>, Option<String>)> {
+        i18n::set_language(language);
+        let ctx = egui::Context::default();
+        ctx.enable_accesskit();
+        let output = ctx.run_ui(Default::default(), |ui| {
+            egui::Grid::new("shows").show(ui, |ui| {
+                Vcc::show_row(ui, 0, show, &[], &mut Vec::new(), &mut None, &mut None);
+            });
+        });
+        output
+            .platform_output
+            .accesskit_update
+            .expect("Unable to get the accessibility tree.")
+            .nodes
+            .into_iter()
+            .map(|(_, node)| {
+                (
+                    node.label().map(str::to_owned),
+                    node.description().map(str::to_owned),
+                )
+            })
+            .collect()
+    }
+
+    fn show(rating: &str) -> DisplayShow {
+        let mut show = DisplayShow::new_numeric(
+            "Dark".to_owned(),
+            2,
+            5,
+            ShowCategory::Watching,
+            &Default::default(),
+        );
+        show.rating = rating.to_owned();
+        show
+    }
+
+    fn labels(language: Language, show: &mut DisplayShow) -> Vec<String> {
+        row_accessibility(language, show)
+            .into_iter()
+            .filter_map(|(label, _)| label)
+            .collect()
+    }
+
+    fn descriptions(language: Language, show: &mut DisplayShow) -> Vec<String> {
+        row_accessibility(language, show)
+            .into_iter()
+            .filter_map(|(_, description)| description)
+            .collect()
+    }
+
+    #[test]
+    fn row_controls_name_their_show() {
+        let labels = labels(Language::English, &mut show(""));
+        for label in [
+            "Delete Dark",
+            "Details of Dark",
+            "Previous season of Dark",
+            "Next season of Dark",
+            "Season number of Dark",
+            "One episode fewer of Dark",
+            "One episode more of Dark",
+            "Episodes seen of Dark",
+            "Rating of Dark",
+            "Category of Dark",
+        ] {
+            assert!(labels.iter().any(|other| other == label), "{}", label);
+        }
+    }
+
+    #[test]
+    fn row_describes_unrated_show() {
+        assert!(
+            descriptions(Language::English, &mut show(""))
+                .iter()
+                .any(|description| description
+                    == "Watching, season 2, 5 episodes seen, not rated, updated Never")
+        );
+    }
+
+    #[test]
+    fn row_describes_rating() {
+        assert!(
+            descriptions(Language::English, &mut show("8"))
+                .iter()
+                .any(|description| description
+                    == "Watching, season 2, 5 episodes seen, rated 8 out of 10, updated Never")
+        );
+        assert!(
+            descriptions(Language::German, &mut show(""))
+                .iter()
+                .any(|description| description.contains(", nicht bewertet,"))
+        );
+    }
 }